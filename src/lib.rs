@@ -23,6 +23,8 @@
 //! let z = lambda!(λ f. (λ x. f (λ y. x x y)) (λ x. f (λ y. x x y)));
 //! ```
 //! 
+//! The [lambda_str!] macro accepts the same syntax, but produces [Term]s with owned [String] identifiers instead.
+//! 
 //! Also provided are the [var!], [abs!], and [app!] macros, which support the same syntax sugar, but mainly operate on expressions rather than raw tokens.
 //! This makes them a better choice for working with pre-defined [Term]s, such as those in the [prelude], while still maintaining readability.
 //! ```
@@ -42,7 +44,7 @@
 //! use lamb::*;
 //! 
 //! let term = lambda!((λ x. z) ((λ w. w w w) (λ w. w w w)));
-//! let reduced = term.beta_reduced(&Normal);
+//! let reduced = term.beta_reduced(&Normal::default());
 //! ```
 //! 
 //! The default `impl`ementations of [BetaReduce] rely on locally nameless representations of [Term]s in order to safely reduce them without needing to α-convert identifiers.
@@ -52,7 +54,7 @@
 //! 
 //! let term = lambda!((λ x. z) ((λ w. w w w) (λ w. w w w)));
 //! let mut local_nameless_term = LocalNamelessTerm::from(&term);
-//! local_nameless_term.beta_reduce(&Normal);
+//! local_nameless_term.beta_reduce(&Normal::default());
 //! ```
 //! 
 //! *Note: Converting a [Term] to a [LocalNamelessTerm] also does not consume the original [Term], leaving it available for further use if necessary.*
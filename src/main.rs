@@ -1,7 +1,10 @@
+use std::fmt::Display;
+use std::hash::Hash;
 use std::io;
 use std::io::Error as IoError;
 use std::io::Write;
 use std::ops::Range;
+use std::time::Duration;
 
 use ariadne::Color;
 use ariadne::ColorGenerator;
@@ -11,20 +14,25 @@ use ariadne::Report;
 use ariadne::ReportKind;
 use ariadne::Source;
 
-use chumsky::Stream;
+use chumsky::error::SimpleReason;
 use chumsky::prelude::*;
 
-use logos::Logos;
-
+use lamb::RightToLeft;
+use lamb::repl::Command;
 use lamb::repl::CommandOutcome;
+use lamb::repl::Expansion;
+use lamb::repl::ImportError;
 use lamb::repl::Repl;
-use lamb::repl::lexer::Token;
-use lamb::repl::parser::*;
+use lamb::repl::TermComparison;
+use lamb::repl::Undone;
+use lamb::repl::parser::CommandParseError;
 
 const REPORT_KIND_INFO: ReportKind = ReportKind::Custom("Info", Color::Green);
 
 fn main() -> Result<(), IoError> {
     let mut repl = Repl::new();
+    repl.register_strategy("right-to-left", RightToLeft);
+    repl.enable_imports();
     let mut color_gen = ColorGenerator::new();
 
     'repl: loop {
@@ -37,33 +45,48 @@ fn main() -> Result<(), IoError> {
             continue;
         }
         
-        let lex_result = Token::lexer(&source).spanned();
-        let parse_result = filler_parser().ignore_then(command_parser())
-            .then_ignore(end())
-            .parse(Stream::from_iter(source.len() - 1..source.len(), lex_result));
-        let command = match parse_result {
+        let command = match Command::try_from(source.as_str()) {
             Ok(command) => command,
-            Err(errors) => {
-                report_syntax_error(&source, errors, &mut color_gen)?;
+            Err(CommandParseError(errors)) => {
+                report_syntax_error(&source, "Invalid syntax", errors, &mut color_gen)?;
                 continue;
             },
         };
         
         for action in repl.exec(command) {
             match action {
-                CommandOutcome::TermReduced(reduced) => {
-                    report_term_reduced(&source, reduced.count)?;
+                CommandOutcome::TermReduced(reduced, expansion) => {
+                    report_term_reduced(&source, reduced.count, expansion, color_gen.next())?;
+                    println!("{}", reduced.term);
+                },
+                CommandOutcome::TermTimed(reduced, elapsed) => {
+                    report_term_timed(&source, reduced.count, elapsed, color_gen.next())?;
                     println!("{}", reduced.term);
                 },
+                CommandOutcome::ValueReported(value) => {
+                    report_value_reported(&source)?;
+                    println!("{}", value);
+                },
                 CommandOutcome::ReduceLimitReached(limit) => report_reduce_limit_reached(&source, limit, color_gen.next())?,
-                CommandOutcome::BindAdded(name) => report_binding_added(&source, name, color_gen.next())?,
-                CommandOutcome::BindOverwritten(name) => {
+                CommandOutcome::NothingToReduce => report_nothing_to_reduce(&source)?,
+                CommandOutcome::BindAdded(name, expansion) => report_binding_added(&source, name, expansion, color_gen.next())?,
+                CommandOutcome::BindOverwritten(name, expansion) => {
                     let color = color_gen.next();
-                    report_binding_added(&source, &name, color)?;
+                    report_binding_added(&source, &name, expansion, color)?;
                     report_binding_overwritten(&source, &name, color)?;
                 },
+                CommandOutcome::Imported(path, count) => report_imported(&source, path, count, color_gen.next())?,
+                CommandOutcome::ImportFailed(path, ImportError::Io(error)) => report_import_read_error(&source, path, error, color_gen.next())?,
+                CommandOutcome::ImportFailed(path, ImportError::Disabled) => report_imports_disabled(&source, path, color_gen.next())?,
+                CommandOutcome::ImportFailed(path, ImportError::Syntax(contents, errors)) => report_syntax_error(contents, format!("Invalid syntax in {}", path), errors, &mut color_gen)?,
                 CommandOutcome::ReduceLimitGot(limit) => report_reduce_limit_got(&source, limit, color_gen.next())?,
                 CommandOutcome::ReduceLimitSet(limit) => report_reduce_limit_set(&source, limit, color_gen.next())?,
+                CommandOutcome::StrategyGot(name) => report_strategy_got(&source, name, color_gen.next())?,
+                CommandOutcome::StrategySet(name) => report_strategy_set(&source, name, color_gen.next())?,
+                CommandOutcome::StrategyNotFound(name) => report_strategy_not_found(&source, name, color_gen.next())?,
+                CommandOutcome::TermsCompared(comparison) => report_terms_compared(&source, comparison)?,
+                CommandOutcome::Undone(undone) => report_undone(&source, undone, color_gen.next())?,
+                CommandOutcome::NothingToUndo => report_nothing_to_undo(&source)?,
                 CommandOutcome::Exit => break 'repl,
             }
         }
@@ -79,22 +102,44 @@ fn report_read_error(source: impl AsRef<str>, error: IoError) -> Result<(), IoEr
         .print(Source::from(source))
 }
 
-fn report_syntax_error(source: impl AsRef<str>, errors: Vec<Simple<Token>>, color_gen: &mut ColorGenerator) -> Result<(), IoError> {
+fn report_syntax_error<I: Display + Hash + Eq>(source: impl AsRef<str>, message: impl ToString, errors: Vec<Simple<I>>, color_gen: &mut ColorGenerator) -> Result<(), IoError> {
     errors.into_iter()
         .fold(Report::build(ReportKind::Error, (), 0)
-            .with_message("Invalid syntax"), |report, error| {
+            .with_message(message), |report, error| {
                 let color = color_gen.next();
+                let message = match error.reason() {
+                    SimpleReason::Custom(message) => message.clone(),
+                    _ => error.to_string(),
+                };
                 report.with_label(Label::new(into_char_span(error.span(), &source))
-                    .with_message(format!("{}", error.fg(color)))
+                    .with_message(format!("{}", message.fg(color)))
                     .with_color(color))
             })
         .finish()
         .eprint(Source::from(source))
 }
 
-fn report_term_reduced(source: impl AsRef<str>, count: usize) -> Result<(), IoError> {
+fn report_term_reduced(source: impl AsRef<str>, count: usize, expansion: Expansion, color: Color) -> Result<(), IoError> {
+    let message = match expansion.substitutions {
+        0 => format!("Reduced {} times", count.fg(Color::Green)),
+        substitutions => format!("Reduced {} times after expanding {} bindings ({} nodes added)", count.fg(Color::Green), substitutions.fg(color), expansion.added_nodes.fg(color)),
+    };
+    Report::<Range<usize>>::build(REPORT_KIND_INFO, (), 0)
+        .with_message(message)
+        .finish()
+        .print(Source::from(source))
+}
+
+fn report_term_timed(source: impl AsRef<str>, count: usize, elapsed: Duration, color: Color) -> Result<(), IoError> {
+    Report::<Range<usize>>::build(REPORT_KIND_INFO, (), 0)
+        .with_message(format!("Reduced {} times in {}", count.fg(Color::Green), format!("{:?}", elapsed).fg(color)))
+        .finish()
+        .print(Source::from(source))
+}
+
+fn report_value_reported(source: impl AsRef<str>) -> Result<(), IoError> {
     Report::<Range<usize>>::build(REPORT_KIND_INFO, (), 0)
-        .with_message(format!("Reduced {} times", count.fg(Color::Green)))
+        .with_message("Reduced to a value")
         .finish()
         .print(Source::from(source))
 }
@@ -110,9 +155,20 @@ fn report_reduce_limit_reached(source: impl AsRef<str>, reduce_limit: usize, col
         .print(Source::from(source))
 }
 
-fn report_binding_added(source: impl AsRef<str>, name: impl AsRef<str>, color: Color) -> Result<(), IoError> {
+fn report_nothing_to_reduce(source: impl AsRef<str>) -> Result<(), IoError> {
+    Report::<Range<usize>>::build(ReportKind::Warning, (), 0)
+        .with_message("No term has been reduced yet")
+        .finish()
+        .print(Source::from(source))
+}
+
+fn report_binding_added(source: impl AsRef<str>, name: impl AsRef<str>, expansion: Expansion, color: Color) -> Result<(), IoError> {
+    let message = match expansion.substitutions {
+        0 => format!("Binding {} added", name.as_ref().fg(color)),
+        substitutions => format!("Binding {} added after expanding {} bindings ({} nodes in total)", name.as_ref().fg(color), substitutions.fg(color), expansion.size.fg(color)),
+    };
     Report::<Range<usize>>::build(REPORT_KIND_INFO, (), 0)
-        .with_message(format!("Binding {} added", name.as_ref().fg(color)))
+        .with_message(message)
         .finish()
         .print(Source::from(source))
 }
@@ -124,6 +180,27 @@ fn report_binding_overwritten(source: impl AsRef<str>, name: impl AsRef<str>, co
         .print(Source::from(source))
 }
 
+fn report_imported(source: impl AsRef<str>, path: impl AsRef<str>, count: usize, color: Color) -> Result<(), IoError> {
+    Report::<Range<usize>>::build(REPORT_KIND_INFO, (), 0)
+        .with_message(format!("Imported {} {} from {}", count.fg(color), if count == 1 { "binding" } else { "bindings" }, path.as_ref().fg(color)))
+        .finish()
+        .print(Source::from(source))
+}
+
+fn report_import_read_error(source: impl AsRef<str>, path: impl AsRef<str>, error: IoError, color: Color) -> Result<(), IoError> {
+    Report::<Range<usize>>::build(ReportKind::Error, (), 0)
+        .with_message(format!("Could not import {}: {}", path.as_ref().fg(color), error))
+        .finish()
+        .eprint(Source::from(source))
+}
+
+fn report_imports_disabled(source: impl AsRef<str>, path: impl AsRef<str>, color: Color) -> Result<(), IoError> {
+    Report::<Range<usize>>::build(ReportKind::Error, (), 0)
+        .with_message(format!("Could not import {}: imports are disabled", path.as_ref().fg(color)))
+        .finish()
+        .eprint(Source::from(source))
+}
+
 fn report_reduce_limit_set(source: impl AsRef<str>, reduce_limit: usize, color: Color) -> Result<(), IoError> {
     Report::<Range<usize>>::build(REPORT_KIND_INFO, (), 0)
         .with_message(format!("Reduction limit set to {}", reduce_limit.fg(color)))
@@ -138,6 +215,58 @@ fn report_reduce_limit_got(source: impl AsRef<str>, reduce_limit: usize, color:
         .print(Source::from(source))
 }
 
+fn report_strategy_got(source: impl AsRef<str>, name: impl AsRef<str>, color: Color) -> Result<(), IoError> {
+    Report::<Range<usize>>::build(REPORT_KIND_INFO, (), 0)
+        .with_message(format!("Current reduction strategy is {}", name.as_ref().fg(color)))
+        .finish()
+        .print(Source::from(source))
+}
+
+fn report_strategy_set(source: impl AsRef<str>, name: impl AsRef<str>, color: Color) -> Result<(), IoError> {
+    Report::<Range<usize>>::build(REPORT_KIND_INFO, (), 0)
+        .with_message(format!("Reduction strategy set to {}", name.as_ref().fg(color)))
+        .finish()
+        .print(Source::from(source))
+}
+
+fn report_strategy_not_found(source: impl AsRef<str>, name: impl AsRef<str>, color: Color) -> Result<(), IoError> {
+    Report::<Range<usize>>::build(ReportKind::Error, (), 0)
+        .with_message(format!("Reduction strategy {} not found", name.as_ref().fg(color)))
+        .finish()
+        .eprint(Source::from(source))
+}
+
+fn report_terms_compared(source: impl AsRef<str>, comparison: TermComparison) -> Result<(), IoError> {
+    let message = match comparison {
+        TermComparison::AlphaEquivalent => "Terms are α-equivalent",
+        TermComparison::BetaEquivalent => "Terms are β-equivalent",
+        TermComparison::Unrelated => "Terms are not β-equivalent within the reduction limit",
+    };
+    Report::<Range<usize>>::build(REPORT_KIND_INFO, (), 0)
+        .with_message(message)
+        .finish()
+        .print(Source::from(source))
+}
+
+fn report_undone(source: impl AsRef<str>, undone: Undone<String>, color: Color) -> Result<(), IoError> {
+    let message = match undone {
+        Undone::Binds(names) => format!("Reverted {} {}", if names.len() == 1 { "binding" } else { "bindings" }, names.join(", ").fg(color)),
+        Undone::ReduceLimit(limit) => format!("Reduction limit restored to {}", limit.fg(color)),
+        Undone::Strategy(name) => format!("Reduction strategy restored to {}", name.fg(color)),
+    };
+    Report::<Range<usize>>::build(REPORT_KIND_INFO, (), 0)
+        .with_message(message)
+        .finish()
+        .print(Source::from(source))
+}
+
+fn report_nothing_to_undo(source: impl AsRef<str>) -> Result<(), IoError> {
+    Report::<Range<usize>>::build(ReportKind::Warning, (), 0)
+        .with_message("Nothing to undo")
+        .finish()
+        .print(Source::from(source))
+}
+
 fn into_char_span(byte_span: Range<usize>, source: impl AsRef<str>) -> Range<usize> {
     let source = source.as_ref();
     source[..byte_span.start()].chars().count()..source[..byte_span.end()].chars().count()
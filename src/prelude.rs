@@ -1,4 +1,37 @@
 //! Commonly used lambda calculus [Term](crate::Term)s.
 
+use std::collections::HashMap;
+
+use crate::Normal;
+use crate::ReducedTerm;
+use crate::Term;
+
+pub mod arithmetic;
 pub mod boolean;
-pub mod combinators;
\ No newline at end of file
+pub mod combinators;
+pub mod either;
+pub mod list;
+pub mod maybe;
+pub mod numeral;
+
+/// Fully β-reduces a [Term] up to a certain limit using the [Normal] strategy, after replacing its free variables with their matching definitions in an environment.
+/// 
+/// This mirrors how the REPL handles bindings, but works with the `&str` identifiers used by the [Term]s in the prelude.
+/// Definitions may refer to other definitions, and are replaced until none remain (see [Term::beta_reduced_with_binds]).
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::collections::HashMap;
+/// 
+/// use lamb::*;
+/// use lamb::prelude::*;
+/// use lamb::prelude::boolean::*;
+/// 
+/// let env = HashMap::from([("not", not()), ("tru", tru())]);
+/// let reduced = eval_with_env(&app!(var!(not), var!(tru)), &env, 100);
+/// assert_eq!(reduced.term, fls());
+/// ```
+pub fn eval_with_env<'s>(term: &Term<&'s str>, env: &HashMap<&'s str, Term<&'s str>>, limit: usize) -> ReducedTerm<&'s str> {
+    term.beta_reduced_with_binds(env, limit, &Normal::default())
+}
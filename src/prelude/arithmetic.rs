@@ -0,0 +1,126 @@
+//! Arithmetic operations on Church-encoded natural number [Term]s.
+
+use crate::Term;
+use crate::prelude::boolean::*;
+use crate::prelude::combinators::vireo;
+use crate::prelude::numeral::*;
+
+/// The addition function.
+/// 
+/// This is α-equivalent to `λm n s z. m s (n s z)`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::arithmetic::*;
+/// use lamb::prelude::numeral::*;
+/// 
+/// let sum = app!(add(), church(2), church(3)).beta_reduced(&Normal::default()).term;
+/// assert!(sum.alpha_eq(&church(5)));
+/// ```
+pub fn add() -> Term<&'static str> {
+    lambda!(λ m n s z. m s (n s z))
+}
+
+/// The multiplication function.
+/// 
+/// This is α-equivalent to `λm n s. m (n s)`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::arithmetic::*;
+/// use lamb::prelude::numeral::*;
+/// 
+/// let product = app!(mul(), church(2), church(3)).beta_reduced(&Normal::default()).term;
+/// assert!(product.alpha_eq(&church(6)));
+/// ```
+pub fn mul() -> Term<&'static str> {
+    lambda!(λ m n s. m (n s))
+}
+
+/// The exponentiation function, which takes a base and an exponent.
+/// 
+/// This is α-equivalent to `λm n. n m`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::arithmetic::*;
+/// use lamb::prelude::numeral::*;
+/// 
+/// // The β-normal form re-uses the same formal parameter for both abstractions, so it is decoded instead of compared
+/// assert_eq!(to_usize(&app!(exp(), church(2), church(3))), Some(8));
+/// ```
+pub fn exp() -> Term<&'static str> {
+    lambda!(λ m n. n m)
+}
+
+/// The predecessor function, which maps zero to itself.
+/// 
+/// This is α-equivalent to `λn. n (λp. pair (p fls) (succ (p fls))) (pair 0 0) tru`, where `pair` is the vireo combinator (see [vireo]).
+/// Starting from a pair of zeros, each application of `n`'s function shifts the pair one step along, so the first element ends up one behind the second.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::arithmetic::*;
+/// use lamb::prelude::numeral::*;
+/// 
+/// let predecessor = app!(pred(), church(3)).beta_reduced(&Normal::default()).term;
+/// assert!(predecessor.alpha_eq(&church(2)));
+/// 
+/// let predecessor = app!(pred(), church(0)).beta_reduced(&Normal::default()).term;
+/// assert!(predecessor.alpha_eq(&church(0)));
+/// ```
+pub fn pred() -> Term<&'static str> {
+    let shift = abs!(p. app!(vireo(), app!(var!(p), fls()), app!(succ(), app!(var!(p), fls()))));
+    abs!(n. app!(var!(n), shift, app!(vireo(), zero(), zero()), tru()))
+}
+
+/// The subtraction function, which saturates at zero.
+/// 
+/// This is α-equivalent to `λm n. n pred m` (see [pred]).
+/// 
+/// This is unrelated to the substitution combinator `S` despite sharing its name (see [combinators::sub](crate::prelude::combinators::sub)).
+/// Since using `sub` after glob importing both this module and [combinators](crate::prelude::combinators) is ambiguous, at least one of them must be imported by name instead.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::arithmetic::*;
+/// use lamb::prelude::numeral::*;
+/// 
+/// let difference = app!(sub(), church(5), church(3)).beta_reduced(&Normal::default()).term;
+/// assert!(difference.alpha_eq(&church(2)));
+/// 
+/// let difference = app!(sub(), church(3), church(5)).beta_reduced(&Normal::default()).term;
+/// assert!(difference.alpha_eq(&church(0)));
+/// ```
+pub fn sub() -> Term<&'static str> {
+    abs!(m n. app!(var!(n), pred(), var!(m)))
+}
+
+/// The zero test, which returns a Church-encoded boolean.
+/// 
+/// This is α-equivalent to `λn. n (λx. fls) tru` (see [fls] and [tru]).
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::arithmetic::*;
+/// use lamb::prelude::boolean::*;
+/// use lamb::prelude::numeral::*;
+/// 
+/// assert_eq!(app!(is_zero(), church(0)).beta_reduced(&Normal::default()).term, tru());
+/// assert_eq!(app!(is_zero(), church(2)).beta_reduced(&Normal::default()).term, fls());
+/// ```
+pub fn is_zero() -> Term<&'static str> {
+    abs!(n. app!(var!(n), abs!(x. fls()), tru()))
+}
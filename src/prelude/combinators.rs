@@ -39,7 +39,7 @@ pub fn omega() -> Term<&'static str> {
 
 /// The reverse application combinator `R`.
 ///
-/// This is α-equivalent to `λx y. y x`.
+/// This is α-equivalent to `λx y. y x`, and is also known as the thrush combinator `T`.
 pub fn app_rev() -> Term<&'static str> {
     lambda!(λ x y. y x)
 }
@@ -91,4 +91,98 @@ pub fn universal() -> Term<&'static str> {
 /// This is α-equivalent to `λx. x x`.
 pub fn app_self() -> Term<&'static str> {
     lambda!(λ x. x x)
-}
\ No newline at end of file
+}
+
+/// The owl combinator `O`.
+///
+/// This is α-equivalent to `λa b. b (a b)`.
+///
+/// # Examples
+///
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::combinators::*;
+///
+/// assert_eq!(owl().to_string(), "λa. λb. b (a b)");
+/// assert_eq!(app!(owl(), var!(f), var!(g)).beta_reduced(&Normal::default()).term, lambda!(g (f g)));
+/// ```
+pub fn owl() -> Term<&'static str> {
+    lambda!(λ a b. b (a b))
+}
+
+/// The vireo or pairing combinator `V`.
+///
+/// This is α-equivalent to `λx y f. f x y`.
+///
+/// # Examples
+///
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::combinators::*;
+///
+/// assert_eq!(vireo().to_string(), "λx. λy. λf. f x y");
+/// assert_eq!(app!(vireo(), var!(a), var!(b), var!(g)).beta_reduced(&Normal::default()).term, lambda!(g a b));
+/// ```
+pub fn vireo() -> Term<&'static str> {
+    lambda!(λ x y f. f x y)
+}
+
+/// The blackbird combinator `B₁`.
+///
+/// This is α-equivalent to `λf g x y. f (g x y)`, i.e. the composition of a unary function with a binary one.
+///
+/// # Examples
+///
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::combinators::*;
+///
+/// assert_eq!(blackbird().to_string(), "λf. λg. λx. λy. f (g x y)");
+/// assert_eq!(app!(blackbird(), var!(h), var!(k), var!(a), var!(b)).beta_reduced(&Normal::default()).term, lambda!(h (k a b)));
+/// ```
+pub fn blackbird() -> Term<&'static str> {
+    lambda!(λ f g x y. f (g x y))
+}
+
+/// The mockingbird combinator `M`.
+///
+/// This is an alias for the self-application combinator `ω` (see [app_self]).
+pub fn mockingbird() -> Term<&'static str> {
+    app_self()
+}
+
+/// Returns the combinator denoted by a symbol in combinatory logic notation, or [None] if the symbol does not denote any combinator.
+///
+/// The recognised symbols are `B`, `C`, `I`, `K`, `M`, `O`, `R`, `S`, `T`, `V`, `W`, `Y`, `Z`, `Θ`, `Ω`, `i`, and `ω`, each of which is listed in the documentation of its combinator.
+///
+/// # Examples
+///
+/// ```
+/// use lamb::prelude::combinators::*;
+///
+/// assert_eq!(by_symbol('S'), Some(sub()));
+/// assert_eq!(by_symbol('M'), Some(app_self()));
+/// assert_eq!(by_symbol('Q'), None);
+/// ```
+pub fn by_symbol(symbol: char) -> Option<Term<&'static str>> {
+    let combinator = match symbol {
+        'B' => compose(),
+        'C' => flip(),
+        'I' => id(),
+        'K' => constant(),
+        'M' => mockingbird(),
+        'O' => owl(),
+        'R' | 'T' => app_rev(),
+        'S' => sub(),
+        'V' => vireo(),
+        'W' => dup(),
+        'Y' => fix_lazy(),
+        'Z' => fix_strict(),
+        'Θ' => fix_turing(),
+        'Ω' => omega(),
+        'i' => universal(),
+        'ω' => app_self(),
+        _ => return None,
+    };
+    Some(combinator)
+}
@@ -0,0 +1,39 @@
+//! Church-encoded sum [Term]s and operations.
+
+use crate::Term;
+
+/// The Church-encoded sum constructor `left`.
+/// 
+/// This is α-equivalent to `λx l r. l x`.
+pub fn left() -> Term<&'static str> {
+    lambda!(λ x l r. l x)
+}
+
+/// The Church-encoded sum constructor `right`.
+/// 
+/// This is α-equivalent to `λy l r. r y`.
+pub fn right() -> Term<&'static str> {
+    lambda!(λ y l r. r y)
+}
+
+/// The `case` eliminator, which takes a sum value and a function for each of its variants.
+/// 
+/// This is α-equivalent to `λs l r. s l r`.
+/// It reduces to the first function applied to the contained value when given a [left] value, and to the second function when given a [right] value.
+/// Since Church-encoded sums encode this functionality in themselves, this is technically redundant.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::either::*;
+/// 
+/// let term = app!(case(), app!(left(), var!(a)), var!(f), var!(g));
+/// assert_eq!(term.beta_reduced(&Normal::default()).term, lambda!(f a));
+/// 
+/// let term = app!(case(), app!(right(), var!(b)), var!(f), var!(g));
+/// assert_eq!(term.beta_reduced(&Normal::default()).term, lambda!(g b));
+/// ```
+pub fn case() -> Term<&'static str> {
+    lambda!(λ s l r. s l r)
+}
@@ -0,0 +1,62 @@
+//! Church-encoded list [Term]s and operations.
+
+use std::iter;
+
+use crate::BetaReduce;
+use crate::LocalNamelessTerm;
+use crate::Term;
+use crate::Var;
+use crate::Whnf;
+
+/// The Church-encoded empty list `nil`.
+/// 
+/// This is α-equivalent to `λc n. n`.
+pub fn nil() -> Term<&'static str> {
+    lambda!(λ c n. n)
+}
+
+/// The Church-encoded list constructor `cons`, which takes an element and a list to prepend it to.
+/// 
+/// This is α-equivalent to `λh t c n. c h (t c n)`, i.e. a list is its own right fold.
+pub fn cons() -> Term<&'static str> {
+    lambda!(λ h t c n. c h (t c n))
+}
+
+/// Lazily decodes the elements of a Church-encoded list (see [cons] and [nil]), from first to last.
+/// 
+/// The list is applied to two placeholder free variables, `#cons` and `#nil`, which cannot appear in [Term]s written using the REPL or [lambda!].
+/// Each call to [Iterator::next] then β-reduces only as much of the list as is needed to expose its next element, stopping once it reaches `#nil`.
+/// Elements are returned exactly as they appear in the list, without being β-reduced themselves.
+/// 
+/// If the list is not Church-encoded, the iterator stops at the first point where it does not resemble a list.
+/// Note that β-reducing the list to expose its next element may not terminate.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::list::*;
+/// 
+/// let list = app!(cons(), var!(a), app!(cons(), var!(b), app!(cons(), var!(c), nil())));
+/// assert_eq!(iter(&list).collect::<Vec<_>>(), vec![lambda!(a), lambda!(b), lambda!(c)]);
+/// 
+/// assert_eq!(iter(&nil()).next(), None);
+/// ```
+pub fn iter<'s>(term: &Term<&'s str>) -> impl Iterator<Item = Term<&'s str>> {
+    let mut rest = Some(LocalNamelessTerm::from(&app!(term.clone(), Term::var("#cons"), Term::var("#nil"))));
+    iter::from_fn(move || {
+        let mut list = rest.take()?;
+        // Only the head of the spine is reduced, leaving the remaining elements untouched
+        Whnf.beta_reduce(&mut list);
+        match list {
+            LocalNamelessTerm::App(func, tail) => match *func {
+                LocalNamelessTerm::App(cons, head) if *cons == LocalNamelessTerm::var(Var::Free("#cons")) => {
+                    rest = Some(*tail);
+                    Term::try_from(&*head).ok()
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    })
+}
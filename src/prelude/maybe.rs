@@ -0,0 +1,38 @@
+//! Church-encoded optional [Term]s and operations.
+
+use crate::Term;
+
+/// The Church-encoded empty optional value `nothing`.
+/// 
+/// This is α-equivalent to `λn j. n`.
+pub fn nothing() -> Term<&'static str> {
+    lambda!(λ n j. n)
+}
+
+/// The Church-encoded optional value constructor `just`.
+/// 
+/// This is α-equivalent to `λx n j. j x`.
+pub fn just() -> Term<&'static str> {
+    lambda!(λ x n j. j x)
+}
+
+/// The `maybe` eliminator, which takes a default value, a function, and an optional value.
+/// 
+/// This is α-equivalent to `λn j m. m n j`.
+/// It reduces to the default value when given [nothing], and to the function applied to the contained value when given [just] a value.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::maybe::*;
+/// 
+/// let some = app!(maybe(), var!(n), lambda!(λ x. x), app!(just(), var!(a)));
+/// assert_eq!(some.beta_reduced(&Normal::default()).term, lambda!(a));
+/// 
+/// let none = app!(maybe(), var!(n), lambda!(λ x. x), nothing());
+/// assert_eq!(none.beta_reduced(&Normal::default()).term, lambda!(n));
+/// ```
+pub fn maybe() -> Term<&'static str> {
+    lambda!(λ n j m. m n j)
+}
@@ -0,0 +1,115 @@
+//! Church-encoded natural number [Term]s and operations.
+
+use crate::BetaReduce;
+use crate::LocalNamelessTerm;
+use crate::Normal;
+use crate::Term;
+
+/// The Church-encoded natural number `n`.
+/// 
+/// This is α-equivalent to `λs z. s (s (... (s z)))`, with `n` applications of `s`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::numeral::*;
+/// 
+/// assert_eq!(church(3), lambda!(λ s z. s (s (s z))));
+/// assert_eq!(church(0), zero());
+/// ```
+pub fn church(n: usize) -> Term<&'static str> {
+    Term::numeral(n, "s", "z")
+}
+
+/// Church-encodes the natural number `n`.
+/// 
+/// This is an alias of [church], and is the inverse of [to_usize].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::numeral::*;
+/// 
+/// assert_eq!(encode(0), zero());
+/// assert_eq!(encode(0), lambda!(λ s z. z));
+/// assert_eq!(to_usize(&encode(4)), Some(4));
+/// ```
+pub fn encode(n: usize) -> Term<&'static str> {
+    Term::numeral(n, "s", "z")
+}
+
+/// The Church-encoded natural number zero.
+/// 
+/// This is α-equivalent to `λs z. z`.
+pub fn zero() -> Term<&'static str> {
+    church(0)
+}
+
+/// The successor function, which takes a Church-encoded natural number and returns the next one.
+/// 
+/// This is α-equivalent to `λn s z. s (n s z)`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::numeral::*;
+/// 
+/// assert_eq!(app!(succ(), church(2)).beta_reduced(&Normal::default()).term, church(3));
+/// ```
+pub fn succ() -> Term<&'static str> {
+    lambda!(λ n s z. s (n s z))
+}
+
+/// The maximum number of β-reduction steps [to_usize] performs before giving up on a [Term].
+pub const TO_USIZE_REDUCE_LIMIT: usize = 10_000;
+
+/// Returns the natural number that a [Term] encodes, or [None] if it does not behave like a Church-encoded natural number.
+/// 
+/// The [Term] is applied to two placeholder free variables, `#s` and `#z` (which cannot appear in [Term]s written using the REPL or [lambda!]), and β-reduced using the [Normal] strategy for at most [TO_USIZE_REDUCE_LIMIT] steps.
+/// The number is then recovered by counting the applications of `#s` to `#z` in the result.
+/// Since the [Term]'s behaviour is examined rather than its shape, [Term]s that β-reduce to a Church-encoded natural number (such as `λs. s` or `succ 2`) are recognised as well.
+/// 
+/// If the [Term] applied to the placeholders does not reach a β-normal form within that limit (such as when it has none at all), [None] is returned.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::prelude::numeral::*;
+/// 
+/// for n in 0..=20 {
+///     assert_eq!(to_usize(&church(n)), Some(n));
+/// }
+/// 
+/// assert_eq!(to_usize(&app!(succ(), church(4))), Some(5));
+/// assert_eq!(to_usize(&Term::abs("s".to_string(), Term::var("s".to_string()))), Some(1));
+/// assert_eq!(to_usize(&lambda!(λ s z. s z z)), None);
+/// assert_eq!(to_usize(&lambda!(λ t f. t)), None);
+/// 
+/// // This has no β-normal form
+/// assert_eq!(to_usize(&lambda!(λ s z. (λ x. x x) (λ x. x x))), None);
+/// ```
+pub fn to_usize<T: Clone + Eq + From<&'static str>>(term: &Term<T>) -> Option<usize> {
+    let (succ, zero) = (Term::var(T::from("#s")), Term::var(T::from("#z")));
+    let applied = Term::app(Term::app(term.clone(), succ.clone()), zero.clone());
+    let mut local_nameless = LocalNamelessTerm::from(&applied);
+    let count = Normal::default().beta_reduce_limit(&mut local_nameless, TO_USIZE_REDUCE_LIMIT);
+    // Reaching the limit exactly at a β-normal form is not a failure, so check whether any β-redexes are left
+    if count >= TO_USIZE_REDUCE_LIMIT && Normal::default().beta_reduce_step(&mut local_nameless) {
+        return None;
+    }
+    let reduced = Term::try_from(&local_nameless).ok()?;
+    let mut body = &reduced;
+    let mut number = 0;
+    loop {
+        match body {
+            Term::App(func, arg) if **func == succ => body = arg,
+            body if *body == zero => return Some(number),
+            _ => return None,
+        }
+        number += 1;
+    }
+}
@@ -1,12 +1,33 @@
 //! [Repl] (read-eval-print-loop) functions for executing [Command]s and handling the results.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::collections::hash_map::Iter as HashMapIter;
+use std::fs;
 use std::hash::Hash;
+use std::io::Error as IoError;
+use std::mem;
+use std::time::Duration;
+use std::time::Instant;
 
+use crate::BetaReduce;
 use crate::LocalNamelessTerm;
 use crate::Normal;
 use crate::ReducedTerm;
+use crate::ReducedValue;
 use crate::Term;
+use crate::Var;
+
+use chumsky::Parser;
+use chumsky::Stream;
+use chumsky::error::Simple;
+
+use logos::Logos;
+
+use crate::repl::lexer::Token;
+use crate::repl::parser::balanced_parens;
+use crate::repl::parser::bindings_parser;
 
 pub mod statement;
 pub use statement::*;
@@ -25,101 +46,857 @@ pub enum Command<T> {
     /// Since this has the potential to cause a stack overflow due to deep recursion, the [Repl] limits the number of β-reduction steps that can be performed (see [Term::beta_reduced_limit]).
     /// However, the limit can be modified.
     Reduce(Term<T>),
+    /// β-reduce a [Term] (see [Command::Reduce]), measuring how long it takes.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::time::Duration;
+    /// 
+    /// use lamb::*;
+    /// use lamb::repl::*;
+    /// 
+    /// let mut repl = Repl::new();
+    /// let outcomes = repl.exec(Command::Time(lambda_str!((λ x. x) ((λ y. y) ((λ z. z) w)))));
+    /// match &outcomes[..] {
+    ///     [CommandOutcome::TermTimed(reduced, elapsed)] => {
+    ///         assert_eq!(reduced.count, 3);
+    ///         assert!(*elapsed > Duration::ZERO);
+    ///     },
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    Time(Term<T>),
+    /// β-reduce a [Term] (see [Command::Reduce]), and interpret the result as a Church-encoded value (see [Term::reduce_to_value]).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// use lamb::repl::*;
+    /// 
+    /// let mut repl = Repl::new();
+    /// repl.exec(Command::Exec(vec![
+    ///     Statement::Bind("plus".to_string(), lambda_str!(λ m n f x. m f (n f x))),
+    ///     Statement::Bind("two".to_string(), lambda_str!(λ f x. f (f x))),
+    ///     Statement::Bind("three".to_string(), lambda_str!(λ f x. f (f (f x)))),
+    /// ]));
+    /// 
+    /// let outcomes = repl.exec(Command::Value(lambda_str!(plus two three)));
+    /// assert!(matches!(&outcomes[..], [CommandOutcome::ValueReported(ReducedValue::Numeral(5))]));
+    /// ```
+    Value(Term<T>),
     /// Execute one or more [Statement]s, updating the [Repl]'s state as necessary.
+    /// 
+    /// This can also be constructed from one or more [Statement]s using [Command]'s [From] `impl`ementations.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// use lamb::repl::*;
+    /// 
+    /// let statement = Statement::Bind("id".to_string(), lambda_str!(λ x. x));
+    /// assert!(matches!(Command::from(vec![statement.clone()]), Command::Exec(statements) if statements.len() == 1));
+    /// assert!(matches!(Command::from(statement), Command::Exec(statements) if statements.len() == 1));
+    /// ```
     Exec(Vec<Statement<T>>),
+    /// Execute one or more [Statement]s (see [Command::Exec]), and then β-reduce a [Term] (see [Command::Reduce]).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use chumsky::Parser;
+    /// use chumsky::Stream;
+    /// 
+    /// use logos::Logos;
+    /// 
+    /// use lamb::*;
+    /// use lamb::repl::*;
+    /// use lamb::repl::lexer::Token;
+    /// use lamb::repl::parser::command_parser;
+    /// 
+    /// let source = "i = λx. x; i i";
+    /// let command = command_parser()
+    ///     .parse(Stream::from_iter(source.len()..source.len(), Token::lexer(source).spanned()))
+    ///     .unwrap();
+    /// 
+    /// let outcomes = Repl::new().exec(command);
+    /// match &outcomes[..] {
+    ///     [CommandOutcome::BindAdded(name, _), CommandOutcome::TermReduced(reduced, _)] => {
+    ///         assert_eq!(name, "i");
+    ///         assert_eq!(reduced.term, lambda_str!(λ x. x));
+    ///     },
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    ExecReduce(Vec<Statement<T>>, Term<T>),
+    /// Continue β-reducing the result of the last [Command] that β-reduced a [Term], up to the specified number of additional steps or the [Repl]'s β-reduction limit if none is specified.
+    /// 
+    /// This is useful when β-reducing a [Term] reached the β-reduction limit.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// use lamb::repl::*;
+    /// 
+    /// let mut repl = Repl::new();
+    /// repl.exec(Command::SetReduceLimit(1));
+    /// 
+    /// let outcomes = repl.exec(Command::Reduce(lambda_str!((λ x. x) ((λ y. y) ((λ z. z) w)))));
+    /// assert!(matches!(&outcomes[..], [CommandOutcome::TermReduced(_, _), CommandOutcome::ReduceLimitReached(1)]));
+    /// 
+    /// let outcomes = repl.exec(Command::ReduceMore(Some(5)));
+    /// match &outcomes[..] {
+    ///     [CommandOutcome::TermReduced(reduced, _)] => {
+    ///         assert_eq!(reduced.term, lambda_str!(w));
+    ///         assert_eq!(reduced.count, 2);
+    ///     },
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    ReduceMore(Option<usize>),
     /// Get the [Repl]'s β-reduction limit.
     GetReduceLimit,
     /// Set the [Repl]'s β-reduction limit.
     SetReduceLimit(usize),
+    /// Get the name of the [Repl]'s current β-reduction strategy.
+    GetStrategy,
+    /// Set the [Repl]'s current β-reduction strategy to the one registered with the specified name (see [Repl::register_strategy]).
+    SetStrategy(String),
+    /// Compare two [Term]s for α-equivalence or β-equivalence.
+    /// 
+    /// Both [Term]s have their free variables replaced with matching bindings (if they exist) before being compared.
+    /// If they are not α-equivalent, they are both β-reduced up to the [Repl]'s β-reduction limit and compared again.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use chumsky::Parser;
+    /// use chumsky::Stream;
+    /// 
+    /// use logos::Logos;
+    /// 
+    /// use lamb::repl::*;
+    /// use lamb::repl::lexer::Token;
+    /// use lamb::repl::parser::command_parser;
+    /// 
+    /// let source = ":diff λx.x | λy.y";
+    /// let command = command_parser()
+    ///     .parse(Stream::from_iter(source.len()..source.len(), Token::lexer(source).spanned()))
+    ///     .unwrap();
+    /// 
+    /// let outcomes = Repl::new().exec(command);
+    /// assert!(matches!(outcomes[..], [CommandOutcome::TermsCompared(TermComparison::AlphaEquivalent)]));
+    /// ```
+    Diff(Term<T>, Term<T>),
+    /// Revert the last [Command] that changed the [Repl]'s bindings, β-reduction limit, or β-reduction strategy.
+    /// 
+    /// Only a limited number of such [Command]s are remembered.
+    /// All bindings added or overwritten by a single [Command] are reverted together.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// use lamb::repl::*;
+    /// 
+    /// let mut repl = Repl::new();
+    /// repl.exec(Command::from(Statement::Bind("id".to_string(), lambda_str!(λ x. x))));
+    /// repl.exec(Command::from(Statement::Bind("id".to_string(), lambda_str!(λ x. x x))));
+    /// 
+    /// let outcomes = repl.exec(Command::Undo);
+    /// assert!(matches!(&outcomes[..], [CommandOutcome::Undone(Undone::Binds(names))] if names == &["id"]));
+    /// match &repl.exec(Command::Reduce(lambda_str!(id)))[..] {
+    ///     [CommandOutcome::TermReduced(reduced, _)] => assert_eq!(reduced.term, lambda_str!(λ x. x)),
+    ///     _ => unreachable!(),
+    /// }
+    /// 
+    /// repl.exec(Command::Undo);
+    /// match &repl.exec(Command::Reduce(lambda_str!(id)))[..] {
+    ///     [CommandOutcome::TermReduced(reduced, _)] => assert_eq!(reduced.term, lambda_str!(id)),
+    ///     _ => unreachable!(),
+    /// }
+    /// 
+    /// assert!(matches!(&repl.exec(Command::Undo)[..], [CommandOutcome::NothingToUndo]));
+    /// ```
+    Undo,
     /// Exit the [Repl].
     Exit,
 }
 
+/// The result of comparing two [Term]s with a [Command::Diff].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TermComparison {
+    /// The [Term]s are α-equivalent, i.e. identical up to the identifiers of their formal parameters.
+    AlphaEquivalent,
+    /// The [Term]s are not α-equivalent, but β-reduce to α-equivalent [Term]s within the β-reduction limit.
+    BetaEquivalent,
+    /// The [Term]s do not β-reduce to α-equivalent [Term]s within the β-reduction limit.
+    Unrelated,
+}
+
+/// Describes how a [Term]'s free variables were replaced with their matching bindings before β-reduction.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Expansion {
+    /// The number of free variables that were replaced.
+    pub substitutions: usize,
+    /// The number of nodes (variables, abstractions, and applications) that the replacements added to the [Term].
+    pub added_nodes: usize,
+    /// The number of nodes in the [Term] after the replacements.
+    pub size: usize,
+}
+
+/// The reasons a [Statement::Import] can fail.
+#[derive(Debug)]
+pub enum ImportError {
+    /// The file could not be read.
+    Io(IoError),
+    /// The file's contents (the first field) could not be parsed into bindings (see [bindings_parser](parser::bindings_parser)), with the second field describing what went wrong.
+    /// 
+    /// The [Token](lexer::Token)s in each error are converted to [String]s, as the contents of the file would otherwise need to outlive the [Repl].
+    Syntax(String, Vec<Simple<String>>),
+    /// Imports were not enabled on the [Repl] (see [Repl::enable_imports]).
+    Disabled,
+}
+
+/// Describes a change to a [Repl]'s state that was reverted by [Command::Undo].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Undone<T> {
+    /// The bindings with the specified names were restored to their previous [Term]s, or removed if they did not exist before.
+    Binds(Vec<T>),
+    /// The β-reduction limit was restored to the specified value.
+    ReduceLimit(usize),
+    /// The β-reduction strategy with the specified name was selected again.
+    Strategy(String),
+}
+
 /// The outcome of a [Repl] executing a [Command].
 /// 
 /// Executing a [Command] can have one or more [CommandOutcome]s, as certain situations are considered warnings by the [Repl], even if the [Command] was executed successfully.
 pub enum CommandOutcome<T> {
-    /// A [Term] was reduced upto the (implied) β-reduction limit.
-    TermReduced(ReducedTerm<T>),
+    /// A [Term] was reduced upto the (implied) β-reduction limit, after its free variables were replaced with their matching bindings as described by the [Expansion].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// use lamb::repl::*;
+    /// 
+    /// let mut repl = Repl::new();
+    /// repl.exec(Command::from(Statement::Bind("three".to_string(), lambda_str!(λ f x. f (f (f x))))));
+    /// 
+    /// let outcomes = repl.exec(Command::Reduce(lambda_str!(three g y)));
+    /// match &outcomes[..] {
+    ///     [CommandOutcome::TermReduced(reduced, expansion)] => {
+    ///         assert_eq!(reduced.term, lambda_str!(g (g (g y))));
+    ///         assert_eq!(reduced.count, 2);
+    ///         assert_eq!(expansion.substitutions, 1);
+    ///         assert_eq!(expansion.added_nodes, 8);
+    ///         assert_eq!(expansion.size, 13);
+    ///     },
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    TermReduced(ReducedTerm<T>, Expansion),
+    /// A [Term] was reduced upto the (implied) β-reduction limit, taking the specified amount of time.
+    TermTimed(ReducedTerm<T>, Duration),
+    /// A [Term] was reduced upto the (implied) β-reduction limit and interpreted as a Church-encoded value.
+    ValueReported(ReducedValue<T>),
     /// The β-reduction limit was reached while β-reducing a [Term].
     /// 
     /// This is considered a warning by the [Repl].
     ReduceLimitReached(usize),
-    /// A [Term] with a specific name was added.
+    /// No [Term] has been β-reduced yet, so there was nothing to continue β-reducing with a [Command::ReduceMore].
+    /// 
+    /// This is considered a warning by the [Repl].
+    NothingToReduce,
+    /// A [Term] with a specific name was added, after its free variables were replaced with their matching bindings as described by the [Expansion].
     /// 
     /// Future execution of [Command::Reduce]s will have the [Term]s' free variables replaced with their matching bindings (if they exist) before β-reduction.
-    BindAdded(T),
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// use lamb::repl::*;
+    /// 
+    /// let mut repl = Repl::new();
+    /// repl.exec(Command::from(Statement::Bind("big".to_string(), lambda_str!(λ f x. f (f (f (f (f x))))))));
+    /// 
+    /// let outcomes = repl.exec(Command::from(Statement::Bind("twice".to_string(), lambda_str!(big big))));
+    /// match &outcomes[..] {
+    ///     [CommandOutcome::BindAdded(name, expansion)] => {
+    ///         assert_eq!(name, "twice");
+    ///         assert_eq!(expansion.substitutions, 2);
+    ///         assert_eq!(expansion.size, 27);
+    ///     },
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    BindAdded(T, Expansion),
     /// A [Term] with a specific name was added (see [CommandOutcome::BindAdded]), but a previous [Term] with the same name existed and was overwritten.
     /// 
     /// This is considered a warning by the [Repl].
-    BindOverwritten(T),
+    BindOverwritten(T, Expansion),
+    /// The file at a path was imported, adding the specified number of bindings (see [Statement::Import]).
+    /// 
+    /// Each of the added bindings is also reported as a [CommandOutcome::BindAdded] or [CommandOutcome::BindOverwritten] before this.
+    Imported(String, usize),
+    /// The file at a path could not be imported, so none of its bindings were added.
+    /// 
+    /// This is considered an error by the [Repl].
+    ImportFailed(String, ImportError),
     /// The [Repl]'s β-reduction limit was retrieved.
     ReduceLimitGot(usize),
     /// The [Repl]'s β-reduction limit was updated.
     ReduceLimitSet(usize),
+    /// The name of the [Repl]'s current β-reduction strategy was retrieved.
+    StrategyGot(String),
+    /// The [Repl]'s current β-reduction strategy was updated.
+    StrategySet(String),
+    /// No β-reduction strategy with a specific name was registered, so the [Repl]'s current β-reduction strategy was left unchanged.
+    /// 
+    /// This is considered an error by the [Repl].
+    StrategyNotFound(String),
+    /// Two [Term]s were compared.
+    TermsCompared(TermComparison),
+    /// The last change to the [Repl]'s state was reverted.
+    Undone(Undone<T>),
+    /// There were no changes to the [Repl]'s state left to revert.
+    /// 
+    /// This is considered a warning by the [Repl].
+    NothingToUndo,
     /// The [Repl] must be exited.
     Exit,
 }
 
 /// A read-eval-print-loop that can execute [Command]s.
+/// 
+/// β-reduction strategies are stored as trait objects by name, so that any [BetaReduce] `impl`ementation can be registered and selected at runtime.
+/// Only the [Normal] strategy (named `normal`) is registered by default, and it is selected initially.
 pub struct Repl<T> {
     binds: HashMap<T, LocalNamelessTerm<T>>,
+    deps: HashMap<T, Vec<T>>,
     reduce_limit: usize,
+    strategies: HashMap<String, Box<dyn BetaReduce<Var<T>>>>,
+    strategy: String,
+    history: VecDeque<Change<T>>,
+    last_reduced: Option<LocalNamelessTerm<T>>,
+    import_name: Option<fn(String) -> T>,
 }
 
-impl<T> Repl<T> {
-    /// Creates a new [Repl] with no bindings and the default β-reduction limit.
+// Each change stores the state it replaced, so that it can be restored
+enum Change<T> {
+    Binds(Vec<BindChange<T>>),
+    ReduceLimit(usize),
+    Strategy(String),
+}
+
+struct BindChange<T> {
+    name: T,
+    previous: Option<LocalNamelessTerm<T>>,
+    previous_deps: Option<Vec<T>>,
+}
+
+const HISTORY_LIMIT: usize = 100;
+
+impl<T: Clone> Repl<T> {
+    /// Creates a new [Repl] with no bindings, the default β-reduction limit, and the [Normal] β-reduction strategy.
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Registers a β-reduction strategy with the specified name, so that it can be selected using [Command::SetStrategy].
+    /// 
+    /// Any strategy previously registered with the same name is replaced.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// use lamb::repl::*;
+    /// 
+    /// struct Lazy;
+    /// 
+    /// impl<T: Clone> BetaReduce<Var<T>> for Lazy {
+    ///     fn beta_reduce_step(&self, _: &mut LocalNamelessTerm<T>) -> bool {
+    ///         false
+    ///     }
+    /// }
+    /// 
+    /// let mut repl = Repl::new();
+    /// repl.register_strategy("lazy", Lazy);
+    /// 
+    /// let outcomes = repl.exec(Command::SetStrategy("lazy".to_string()));
+    /// assert!(matches!(&outcomes[..], [CommandOutcome::StrategySet(name)] if name == "lazy"));
+    /// 
+    /// let outcomes = repl.exec(Command::Reduce(lambda_str!((λ x. x) y)));
+    /// match &outcomes[..] {
+    ///     [CommandOutcome::TermReduced(reduced, _)] => assert_eq!(reduced.count, 0),
+    ///     _ => unreachable!(),
+    /// }
+    /// 
+    /// let outcomes = repl.exec(Command::SetStrategy("eager".to_string()));
+    /// assert!(matches!(&outcomes[..], [CommandOutcome::StrategyNotFound(name)] if name == "eager"));
+    /// ```
+    pub fn register_strategy<B>(&mut self, name: impl Into<String>, strategy: B)
+    where
+        B: BetaReduce<Var<T>> + 'static, {
+            self.strategies.insert(name.into(), Box::new(strategy));
+        }
+
+    /// Enables [Statement::Import]s, converting the names read from imported files using [From].
+    /// 
+    /// Imports are disabled by default, and executing a [Statement::Import] before enabling them results in [ImportError::Disabled].
+    /// 
+    /// # Examples
+    /// 
+    /// Importing a file with a prefix makes its bindings available under qualified names.
+    /// 
+    /// ```
+    /// use std::env;
+    /// use std::fs;
+    /// 
+    /// use lamb::*;
+    /// use lamb::repl::*;
+    /// 
+    /// let path = env::temp_dir().join("lamb-import-example.lamb");
+    /// fs::write(&path, "zero = λf x. x;\nsucc = λn f x. f (n f x);\none = succ zero;\n").unwrap();
+    /// 
+    /// let mut repl = Repl::new();
+    /// let outcomes = repl.exec(Command::from(Statement::Import(path.to_str().unwrap().to_string(), None)));
+    /// assert!(matches!(&outcomes[..], [CommandOutcome::ImportFailed(_, ImportError::Disabled)]));
+    /// 
+    /// repl.enable_imports();
+    /// repl.exec(Command::from(Statement::Bind("succ".to_string(), lambda_str!(λ x. x))));
+    /// 
+    /// let import = Statement::Import(path.to_str().unwrap().to_string(), Some("nat".to_string()));
+    /// let outcomes = repl.exec(Command::from(import));
+    /// assert!(matches!(&outcomes[..], [.., CommandOutcome::BindAdded(name, _), CommandOutcome::Imported(_, 3)] if name == "nat.one"));
+    /// 
+    /// let Ok(command) = Command::try_from("nat.succ nat.one") else { unreachable!() };
+    /// match &repl.exec(command)[..] {
+    ///     [CommandOutcome::TermReduced(reduced, _)] => assert_eq!(reduced.term, lambda_str!(λ f x. f (f x))),
+    ///     _ => unreachable!(),
+    /// }
+    /// 
+    /// // The unqualified binding is left untouched
+    /// match &repl.exec(Command::Reduce(lambda_str!(succ y)))[..] {
+    ///     [CommandOutcome::TermReduced(reduced, _)] => assert_eq!(reduced.term, lambda_str!(y)),
+    ///     _ => unreachable!(),
+    /// }
+    /// 
+    /// fs::remove_file(&path).unwrap();
+    /// let outcomes = repl.exec(Command::from(Statement::Import(path.to_str().unwrap().to_string(), None)));
+    /// assert!(matches!(&outcomes[..], [CommandOutcome::ImportFailed(_, ImportError::Io(_))]));
+    /// ```
+    pub fn enable_imports(&mut self)
+    where
+        T: From<String>, {
+            self.import_name = Some(T::from);
+        }
 }
 
 impl<T: Clone + Eq + Hash> Repl<T> {
     /// Executes a [Command] on the [Repl].
     /// 
     /// The resulting [Vec] will always have at least one [CommandOutcome].
+    /// 
+    /// # Examples
+    /// 
+    /// [Command]s can be executed on a [Repl] with any kind of names.
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// use lamb::repl::*;
+    /// 
+    /// let mut repl = Repl::<&'static str>::new();
+    /// repl.exec(Command::from(Statement::Bind("id", lambda!(λ x. x))));
+    /// 
+    /// let outcomes = repl.exec(Command::Reduce(lambda!(id id y)));
+    /// match &outcomes[..] {
+    ///     [CommandOutcome::TermReduced(reduced, _)] => assert_eq!(reduced.term, lambda!(y)),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
     pub fn exec(&mut self, command: Command<T>) -> Vec<CommandOutcome<T>> {
         let mut actions = Vec::with_capacity(1);
         match command {
-            Command::Reduce(term) => {
-                let mut local_nameless = LocalNamelessTerm::from(&term);
-                local_nameless.rebind(&mut self.binds);
-                let count = local_nameless.beta_reduce_limit(self.reduce_limit, &Normal);
-                actions.push(CommandOutcome::TermReduced(ReducedTerm {
-                    count,
-                    term: Term::try_from(&local_nameless).unwrap(),
-                }));
+            Command::Reduce(term) => self.exec_reduce(&term, &mut actions),
+            Command::Time(term) => {
+                let start = Instant::now();
+                let (reduced, _) = self.reduced(&term);
+                let elapsed = start.elapsed();
+                let count = reduced.count;
+                actions.push(CommandOutcome::TermTimed(reduced, elapsed));
                 if count >= self.reduce_limit {
                     actions.push(CommandOutcome::ReduceLimitReached(count));
                 }
             },
-            Command::Exec(statements) => actions.extend(statements.into_iter()
-                .map(|statement| match statement {
-                    Statement::Bind(name, term) => {
-                        let mut local_nameless = LocalNamelessTerm::from(&term);
-                        local_nameless.rebind(&mut self.binds);
-                        match self.binds.insert(name.clone(), local_nameless) {
-                            None => CommandOutcome::BindAdded(name),
-                            Some(_) => CommandOutcome::BindOverwritten(name),
-                        }
-                    },
-                })),
+            Command::Value(term) => {
+                let (reduced, _) = self.reduced(&term);
+                actions.push(CommandOutcome::ValueReported(reduced.term.to_value()));
+                if reduced.count >= self.reduce_limit {
+                    actions.push(CommandOutcome::ReduceLimitReached(reduced.count));
+                }
+            },
+            Command::Exec(statements) => self.exec_statements(statements, &mut actions),
+            Command::ExecReduce(statements, term) => {
+                self.exec_statements(statements, &mut actions);
+                self.exec_reduce(&term, &mut actions);
+            },
+            Command::ReduceMore(limit) => match self.last_reduced.take() {
+                Some(mut local_nameless) => {
+                    let limit = limit.unwrap_or(self.reduce_limit);
+                    let count = self.strategy().beta_reduce_limit(&mut local_nameless, limit);
+                    let reduced = ReducedTerm {
+                        count,
+                        term: Term::try_from(&local_nameless).unwrap(),
+                    };
+                    self.last_reduced = Some(local_nameless);
+                    actions.push(CommandOutcome::TermReduced(reduced, Expansion::default()));
+                    if count >= limit {
+                        actions.push(CommandOutcome::ReduceLimitReached(count));
+                    }
+                },
+                None => actions.push(CommandOutcome::NothingToReduce),
+            },
             Command::GetReduceLimit => actions.push(CommandOutcome::ReduceLimitGot(self.reduce_limit)),
             Command::SetReduceLimit(limit) => {
+                self.record(Change::ReduceLimit(self.reduce_limit));
                 self.reduce_limit = limit;
                 actions.push(CommandOutcome::ReduceLimitSet(limit));
             },
+            Command::GetStrategy => actions.push(CommandOutcome::StrategyGot(self.strategy.clone())),
+            Command::SetStrategy(name) => if self.strategies.contains_key(&name) {
+                let previous = mem::replace(&mut self.strategy, name.clone());
+                self.record(Change::Strategy(previous));
+                actions.push(CommandOutcome::StrategySet(name));
+            } else {
+                actions.push(CommandOutcome::StrategyNotFound(name));
+            },
+            Command::Diff(left, right) => {
+                let mut left = self.rebound(&left);
+                let mut right = self.rebound(&right);
+                if left.nameless_eq(&right) {
+                    actions.push(CommandOutcome::TermsCompared(TermComparison::AlphaEquivalent));
+                } else {
+                    let count = self.strategy().beta_reduce_limit(&mut left, self.reduce_limit)
+                        .max(self.strategy().beta_reduce_limit(&mut right, self.reduce_limit));
+                    let comparison = if left.nameless_eq(&right) {
+                        TermComparison::BetaEquivalent
+                    } else {
+                        TermComparison::Unrelated
+                    };
+                    actions.push(CommandOutcome::TermsCompared(comparison));
+                    if count >= self.reduce_limit {
+                        actions.push(CommandOutcome::ReduceLimitReached(count));
+                    }
+                }
+            },
+            Command::Undo => actions.push(match self.history.pop_back() {
+                Some(change) => CommandOutcome::Undone(self.undo(change)),
+                None => CommandOutcome::NothingToUndo,
+            }),
             Command::Exit => actions.push(CommandOutcome::Exit),
         }
         actions
     }
+
+    /// Removes all bindings that are not reachable from the bindings with the specified names.
+    /// 
+    /// A binding is reachable if it is one of the roots, or if a reachable binding referred to it (as a free variable) when it was bound.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// use lamb::repl::*;
+    /// 
+    /// let mut repl = Repl::new();
+    /// repl.exec(Command::Exec(vec![
+    ///     Statement::Bind("a".to_string(), lambda_str!(λ x. x)),
+    ///     Statement::Bind("b".to_string(), lambda_str!(a a)),
+    ///     Statement::Bind("c".to_string(), lambda_str!(λ x y. x)),
+    /// ]));
+    /// repl.gc(&["b".to_string()]);
+    /// 
+    /// let outcomes = repl.exec(Command::Reduce(lambda_str!(a c)));
+    /// match &outcomes[..] {
+    ///     [CommandOutcome::TermReduced(reduced, _)] => assert_eq!(reduced.term, lambda_str!(c)),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn gc(&mut self, roots: &[T]) {
+        let mut reachable = roots.iter()
+            .filter(|root| self.binds.contains_key(root))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut index = 0;
+        while let Some(name) = reachable.get(index) {
+            let deps = self.deps.get(name)
+                .into_iter()
+                .flatten()
+                .filter(|dep| self.binds.contains_key(dep) && !reachable.contains(dep))
+                .cloned()
+                .collect::<Vec<_>>();
+            reachable.extend(deps);
+            index += 1;
+        }
+        self.binds.retain(|name, _| reachable.contains(name));
+        self.deps.retain(|name, _| reachable.contains(name));
+    }
+
+    fn exec_reduce(&mut self, term: &Term<T>, actions: &mut Vec<CommandOutcome<T>>) {
+        let (reduced, expansion) = self.reduced(term);
+        let count = reduced.count;
+        actions.push(CommandOutcome::TermReduced(reduced, expansion));
+        if count >= self.reduce_limit {
+            actions.push(CommandOutcome::ReduceLimitReached(count));
+        }
+    }
+
+    fn exec_statements(&mut self, statements: Vec<Statement<T>>, actions: &mut Vec<CommandOutcome<T>>) {
+        let mut changes = Vec::with_capacity(statements.len());
+        for statement in statements {
+            match statement {
+                Statement::Bind(name, term) => self.exec_bind(name, &term, actions, &mut changes),
+                Statement::Import(path, prefix) => match self.import_name.ok_or(ImportError::Disabled).and_then(|name| Ok((name, read_bindings(&path)?))) {
+                    Ok((import_name, binds)) => {
+                        let names = binds.iter()
+                            .map(|(name, _)| name.clone())
+                            .collect::<HashSet<_>>();
+                        let count = binds.len();
+                        for (name, term) in binds {
+                            let name = qualified_name(name, prefix.as_deref());
+                            let term = qualified(&term, prefix.as_deref(), &names, import_name, &mut Vec::new());
+                            self.exec_bind(import_name(name), &term, actions, &mut changes);
+                        }
+                        actions.push(CommandOutcome::Imported(path, count));
+                    },
+                    Err(error) => actions.push(CommandOutcome::ImportFailed(path, error)),
+                },
+            }
+        }
+        // Failed imports do not change any bindings, so there may be nothing to undo
+        if !changes.is_empty() {
+            self.record(Change::Binds(changes));
+        }
+    }
+
+    fn exec_bind(&mut self, name: T, term: &Term<T>, actions: &mut Vec<CommandOutcome<T>>, changes: &mut Vec<BindChange<T>>) {
+        let mut deps = Vec::new();
+        bound_free_vars(&LocalNamelessTerm::from(term), &self.binds, &mut deps);
+        let previous_deps = self.deps.insert(name.clone(), deps);
+        let (local_nameless, expansion) = self.expanded(term);
+        let previous = self.binds.insert(name.clone(), local_nameless);
+        actions.push(match previous {
+            None => CommandOutcome::BindAdded(name.clone(), expansion),
+            Some(_) => CommandOutcome::BindOverwritten(name.clone(), expansion),
+        });
+        changes.push(BindChange {
+            name,
+            previous,
+            previous_deps,
+        });
+    }
+
+    fn record(&mut self, change: Change<T>) {
+        if self.history.len() == HISTORY_LIMIT {
+            self.history.pop_front();
+        }
+        self.history.push_back(change);
+    }
+
+    fn undo(&mut self, change: Change<T>) -> Undone<T> {
+        match change {
+            Change::Binds(changes) => Undone::Binds(changes.into_iter()
+                .rev()
+                .map(|BindChange { name, previous, previous_deps }| {
+                    match previous {
+                        Some(previous) => self.binds.insert(name.clone(), previous),
+                        None => self.binds.remove(&name),
+                    };
+                    match previous_deps {
+                        Some(previous_deps) => self.deps.insert(name.clone(), previous_deps),
+                        None => self.deps.remove(&name),
+                    };
+                    name
+                })
+                .collect()),
+            Change::ReduceLimit(limit) => {
+                self.reduce_limit = limit;
+                Undone::ReduceLimit(limit)
+            },
+            Change::Strategy(name) => {
+                self.strategy = name.clone();
+                Undone::Strategy(name)
+            },
+        }
+    }
+
+    fn reduced(&mut self, term: &Term<T>) -> (ReducedTerm<T>, Expansion) {
+        let (mut local_nameless, expansion) = self.expanded(term);
+        let reduced = ReducedTerm {
+            count: self.strategy().beta_reduce_limit(&mut local_nameless, self.reduce_limit),
+            term: Term::try_from(&local_nameless).unwrap(),
+        };
+        self.last_reduced = Some(local_nameless);
+        (reduced, expansion)
+    }
+
+    fn strategy(&self) -> &dyn BetaReduce<Var<T>> {
+        self.strategies[&self.strategy].as_ref()
+    }
+
+    fn rebound(&mut self, term: &Term<T>) -> LocalNamelessTerm<T> {
+        self.expanded(term).0
+    }
+
+    fn expanded(&mut self, term: &Term<T>) -> (LocalNamelessTerm<T>, Expansion) {
+        let mut local_nameless = LocalNamelessTerm::from(term);
+        let size = local_nameless.size();
+        let substitutions = local_nameless.rebind(&mut self.binds);
+        let expansion = Expansion {
+            substitutions,
+            added_nodes: local_nameless.size() - size,
+            size: local_nameless.size(),
+        };
+        (local_nameless, expansion)
+    }
+}
+
+fn bound_free_vars<T: Clone + Eq + Hash>(term: &LocalNamelessTerm<T>, binds: &HashMap<T, LocalNamelessTerm<T>>, vars: &mut Vec<T>) {
+    match term {
+        Term::Var(Var::Bound(_)) => (),
+        Term::Var(Var::Free(var)) => if binds.contains_key(var) && !vars.contains(var) {
+            vars.push(var.clone());
+        },
+        Term::Abs(_, body) => bound_free_vars(body, binds, vars),
+        Term::App(func, arg) => {
+            bound_free_vars(func, binds, vars);
+            bound_free_vars(arg, binds, vars);
+        },
+    }
+}
+
+fn read_bindings(path: &str) -> Result<Vec<(String, Term<String>)>, ImportError> {
+    let source = fs::read_to_string(path).map_err(ImportError::Io)?;
+    let parsed = balanced_parens(Token::lexer(&source).spanned())
+        .and_then(|_| bindings_parser().parse(Stream::from_iter(source.len()..source.len(), Token::lexer(&source).spanned())));
+    match parsed {
+        Ok(binds) => Ok(binds),
+        Err(errors) => {
+            let errors = errors.into_iter()
+                .map(|error| error.map(|token| token.to_string()))
+                .collect();
+            Err(ImportError::Syntax(source, errors))
+        },
+    }
+}
+
+fn qualified_name(name: String, prefix: Option<&str>) -> String {
+    match prefix {
+        Some(prefix) => format!("{}.{}", prefix, name),
+        None => name,
+    }
+}
+
+// Qualifies the free variables that refer to bindings from the same imported file
+fn qualified<'t, T>(term: &'t Term<String>, prefix: Option<&str>, names: &HashSet<String>, import_name: fn(String) -> T, bound: &mut Vec<&'t String>) -> Term<T> {
+    match term {
+        Term::Var(var) if names.contains(var) && !bound.contains(&var) => Term::var(import_name(qualified_name(var.clone(), prefix))),
+        Term::Var(var) => Term::var(import_name(var.clone())),
+        Term::Abs(param, body) => {
+            bound.push(param);
+            let body = qualified(body, prefix, names, import_name, bound);
+            bound.pop();
+            Term::abs(import_name(param.clone()), body)
+        },
+        Term::App(func, arg) => Term::app(qualified(func, prefix, names, import_name, bound), qualified(arg, prefix, names, import_name, bound)),
+    }
+}
+
+/// An iterator over the bindings of a [Repl], created by its [IntoIterator] `impl`ementation.
+/// 
+/// Each binding is yielded as its name along with its [Term], in an arbitrary order.
+/// Since bindings have their free variables replaced with earlier bindings when they are added, the yielded [Term]s are already expanded.
+pub struct Bindings<'r, T> {
+    binds: HashMapIter<'r, T, LocalNamelessTerm<T>>,
+}
+
+impl<'r, T: Clone> Iterator for Bindings<'r, T> {
+    type Item = (&'r T, Term<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.binds.next()
+            .map(|(name, term)| (name, Term::try_from(term).unwrap()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.binds.size_hint()
+    }
+}
+
+/// Iterates over the [Repl]'s bindings (see [Bindings]).
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::repl::*;
+/// 
+/// let mut repl = Repl::new();
+/// repl.exec(Command::Exec(vec![
+///     Statement::Bind("id".to_string(), lambda_str!(λ x. x)),
+///     Statement::Bind("k".to_string(), lambda_str!(λ x y. x)),
+/// ]));
+/// 
+/// let mut binds = repl.into_iter().collect::<Vec<_>>();
+/// binds.sort_by(|(left, _), (right, _)| left.cmp(right));
+/// assert_eq!(binds, vec![
+///     (&"id".to_string(), lambda_str!(λ x. x)),
+///     (&"k".to_string(), lambda_str!(λ x y. x)),
+/// ]);
+/// ```
+impl<'r, T: Clone> IntoIterator for &'r Repl<T> {
+    type Item = (&'r T, Term<T>);
+    type IntoIter = Bindings<'r, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Bindings {
+            binds: self.binds.iter(),
+        }
+    }
+}
+
+impl<T> From<Vec<Statement<T>>> for Command<T> {
+    fn from(statements: Vec<Statement<T>>) -> Self {
+        Self::Exec(statements)
+    }
+}
+
+impl<T> From<Statement<T>> for Command<T> {
+    fn from(statement: Statement<T>) -> Self {
+        Self::Exec(vec![statement])
+    }
 }
 
-impl<T> Default for Repl<T> {
+impl<T: Clone> Default for Repl<T> {
     fn default() -> Self {
+        let mut strategies: HashMap<_, Box<dyn BetaReduce<Var<T>>>> = HashMap::default();
+        strategies.insert("normal".to_string(), Box::new(Normal::default()));
         Self {
             binds: HashMap::default(),
+            deps: HashMap::default(),
             reduce_limit: 1000,
+            strategies,
+            strategy: "normal".to_string(),
+            history: VecDeque::default(),
+            last_reduced: None,
+            import_name: None,
         }
     }
 }
\ No newline at end of file
@@ -21,6 +21,18 @@ pub enum Token<'s> {
     /// An identifier conforming to the regular expression `[a-z][a-zA-Z\-]*`.
     #[regex(r"[a-z][a-zA-Z\-]*")]
     Ident(&'s str),
+    /// An identifier conforming to the regular expression `[A-Z][a-zA-Z\-]*`.
+    /// 
+    /// This is a separate [Token] from [Token::Ident] so that names following the conventions for combinators (such as `S` and `K`) can be recognised without changing what counts as a regular identifier.
+    /// The parsers in [parser](crate::repl::parser) accept both kinds of identifiers wherever an identifier is expected.
+    #[regex(r"[A-Z][a-zA-Z\-]*")]
+    UpperIdent(&'s str),
+    /// An underscore (`_`).
+    /// 
+    /// This is only valid as the formal parameter of an abstraction that discards its argument, such as `λ_. x`, and cannot be used as a variable.
+    /// As a result, nothing can ever refer to an underscore parameter, so multiple underscores in the same [Term](crate::Term) are always distinct.
+    #[token("_")]
+    Underscore,
     /// A dot (`.`).
     #[token(".")]
     Dot,
@@ -56,6 +68,19 @@ pub enum Token<'s> {
     /// If the number exceeds the bounds of [usize], a [Token::Unknown] is emitted instead.
     #[regex("[0-9]+", Token::number)]
     Number(usize),
+    /// A negative or decimal number, such as `-3` or `3.5`.
+    /// 
+    /// Such numbers are never valid, but are recognised separately from [Token::Unknown] so that the parser can report clearer errors for them.
+    #[regex(r"-[0-9]+(\.[0-9]+)?|[0-9]+\.[0-9]+")]
+    InvalidNumber(&'s str),
+    /// A vertical bar (`|`).
+    #[token("|")]
+    Pipe,
+    /// A file path enclosed in double quotes, such as `"numerals.lamb"`.
+    /// 
+    /// The quotes are not part of the path, and the path cannot contain double quotes itself.
+    #[regex(r#""[^"\n]*""#, Token::path)]
+    Path(&'s str),
     /// A [Token] that does not match any of the other valid variants.
     /// 
     /// This indicates some sort of syntax error.
@@ -68,6 +93,11 @@ impl Token<'_> {
         &lexer.slice()[1..]
     }
 
+    fn path<'s>(lexer: &Lexer<'s, Token<'s>>) -> &'s str {
+        let slice = lexer.slice();
+        &slice[1..slice.len() - 1]
+    }
+
     fn number<'s>(lexer: &Lexer<'s, Token<'s>>) -> Option<usize> {
         lexer.slice()
             .parse()
@@ -80,6 +110,8 @@ impl Display for Token<'_> {
         let str = match self {
             Self::Lambda => "λ",
             Self::Ident(ident) => ident,
+            Self::UpperIdent(ident) => ident,
+            Self::Underscore => "_",
             Self::Dot => ".",
             Self::OpenParens => "(",
             Self::CloseParens => ")",
@@ -89,6 +121,9 @@ impl Display for Token<'_> {
             Self::LineComment(_) => "comment",
             Self::Colon => ":",
             Self::Number(_) => "number",
+            Self::InvalidNumber(_) => "invalid number",
+            Self::Pipe => "|",
+            Self::Path(_) => "path",
             Self::Unknown => "unknown",
         };
         write!(formatter, "{}", str)
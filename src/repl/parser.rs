@@ -1,7 +1,14 @@
 //! Parser for parsing [Token]s into [Term]s, [Statement]s, or [Command]s.
 
+use std::collections::HashMap;
+use std::ops::Range;
+
+use chumsky::Stream;
 use chumsky::prelude::*;
 
+use logos::Logos;
+
+use crate::Direction;
 use crate::Term;
 use crate::repl::Command;
 use crate::repl::Statement;
@@ -11,68 +18,623 @@ use crate::repl::lexer::Token;
 pub fn command_parser<'s>() -> impl Parser<Token<'s>, Command<String>, Error = Simple<Token<'s>>> {
     let exec = statement_parser().repeated()
         .at_least(1)
-        .map(Command::Exec);
+        .then(term_parser().or_not())
+        .map(|(statements, term)| match term {
+            Some(term) => Command::ExecReduce(statements, term),
+            None => Command::Exec(statements),
+        });
 
     let reduce = term_parser().map(Command::Reduce);
 
     let exit = just(Token::Ident("exit")).ignore_then(filler_parser())
         .to(Command::Exit);
     
+    let invalid_limit = select! {
+        Token::InvalidNumber(_) => (),
+    };
+    let invalid_limit = invalid_limit.validate(|_, span, emit| {
+            emit(Simple::custom(span, "reduction limit must be a non-negative integer"));
+            0
+        })
+        .then_ignore(filler_parser());
+    
     let limit = just(Token::Ident("limit")).ignore_then(filler_parser())
-        .ignore_then(number_parser().or_not())
+        .ignore_then(number_parser().or(invalid_limit).or_not())
         .map(|limit| match limit {
             Some(limit) => Command::SetReduceLimit(limit),
             None => Command::GetReduceLimit,
         });
     
+    let time = just(Token::Ident("time")).ignore_then(filler_parser())
+        .ignore_then(term_parser())
+        .map(Command::Time);
+    
+    let value = just(Token::Ident("value")).ignore_then(filler_parser())
+        .ignore_then(term_parser())
+        .map(Command::Value);
+    
+    let diff = just(Token::Ident("diff")).ignore_then(filler_parser())
+        .ignore_then(term_parser())
+        .then_ignore(just(Token::Pipe).then_ignore(filler_parser()))
+        .then(term_parser())
+        .map(|(left, right)| Command::Diff(left, right));
+    
+    let more = just(Token::Ident("more")).ignore_then(filler_parser())
+        .ignore_then(number_parser().or_not())
+        .map(Command::ReduceMore);
+    
+    let undo = just(Token::Ident("undo")).ignore_then(filler_parser())
+        .to(Command::Undo);
+    
+    let strategy = just(Token::Ident("strategy")).ignore_then(filler_parser())
+        .ignore_then(ident_parser().or_not())
+        .map(|name| match name {
+            Some(name) => Command::SetStrategy(name),
+            None => Command::GetStrategy,
+        });
+    
     exec.or(reduce)
         .or(just(Token::Colon).ignore_then(filler_parser())
-            .ignore_then(exit.or(limit)))
+            .ignore_then(choice((exit, limit, time, value, more, diff, strategy, undo))))
         .then_ignore(filler_parser())
 }
 
+/// The errors encountered while parsing a [Command] from a string (see [Command]'s [TryFrom] `impl`ementation).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommandParseError<'s>(pub Vec<Simple<Token<'s>>>);
+
+/// Parses a string into a [Command], ignoring all whitespace and line comments around it.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::repl::*;
+/// 
+/// assert!(matches!(Command::try_from(":exit"), Ok(Command::Exit)));
+/// assert!(matches!(Command::try_from("id = λx. x;"), Ok(Command::Exec(statements)) if statements == vec![Statement::Bind("id".to_string(), lambda_str!(λ x. x))]));
+/// assert!(matches!(Command::try_from("(λx. x) y"), Ok(Command::Reduce(term)) if term == lambda_str!((λ x. x) y)));
+/// assert!(Command::try_from("λx.").is_err());
+/// ```
+/// 
+/// An underscore (`_`) can be used as the formal parameter of abstractions that discard their argument, but not as a variable.
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::repl::*;
+/// 
+/// let Ok(Command::Reduce(term)) = Command::try_from("(λ_ _. x) y z") else { unreachable!() };
+/// assert_eq!(term.to_string(), "(λ_. λ_. x) y z");
+/// assert_eq!(term.beta_reduced(&Normal::default()).into_term(), lambda_str!(x));
+/// 
+/// assert!(Command::try_from("(λ_. _) y").is_err());
+/// assert!(Command::try_from("_ = λx. x;").is_err());
+/// ```
+/// 
+/// Identifiers may also start with an uppercase letter, following the conventions for combinators.
+/// 
+/// ```
+/// use lamb::*;
+/// use lamb::repl::*;
+/// 
+/// let mut repl = Repl::new();
+/// let Ok(command) = Command::try_from("S = λx y z. x z (y z); K = λx y. x;") else { unreachable!() };
+/// assert!(matches!(&repl.exec(command)[..], [CommandOutcome::BindAdded(name, _), CommandOutcome::BindAdded(_, _)] if name == "S"));
+/// 
+/// let Ok(command) = Command::try_from("S K K a") else { unreachable!() };
+/// match &repl.exec(command)[..] {
+///     [CommandOutcome::TermReduced(reduced, _)] => assert_eq!(reduced.term, lambda_str!(a)),
+///     _ => unreachable!(),
+/// }
+/// ```
+/// 
+/// Negative and decimal reduction limits are rejected with a specific error message.
+/// 
+/// ```
+/// use chumsky::error::SimpleReason;
+/// 
+/// use lamb::repl::*;
+/// use lamb::repl::parser::CommandParseError;
+/// 
+/// for source in [":limit -3", ":limit 3.5"] {
+///     match Command::try_from(source) {
+///         Err(CommandParseError(errors)) => assert!(matches!(
+///             errors[0].reason(),
+///             SimpleReason::Custom(message) if message == "reduction limit must be a non-negative integer",
+///         )),
+///         Ok(_) => unreachable!(),
+///     }
+/// }
+/// ```
+impl<'s> TryFrom<&'s str> for Command<String> {
+    type Error = CommandParseError<'s>;
+
+    fn try_from(source: &'s str) -> Result<Self, Self::Error> {
+        balanced_parens(Token::lexer(source).spanned())
+            .map_err(CommandParseError)?;
+        filler_parser().ignore_then(command_parser())
+            .then_ignore(end())
+            .parse(Stream::from_iter(source.len()..source.len(), Token::lexer(source).spanned()))
+            .map_err(CommandParseError)
+    }
+}
+
+/// Checks that every opening parenthesis in a sequence of [Token]s is matched by a closing one and vice versa.
+/// 
+/// Unlike the errors produced by the parsers themselves, the errors returned here point at the unmatched parentheses, rather than where the parser gave up.
+/// This check is performed before parsing when converting a string to a [Command] (see [Command]'s [TryFrom] `impl`ementation), but can be used before any other parser as well.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chumsky::error::SimpleReason;
+/// 
+/// use logos::Logos;
+/// 
+/// use lamb::repl::lexer::Token;
+/// use lamb::repl::parser::balanced_parens;
+/// 
+/// assert!(balanced_parens(Token::lexer("(λx. x) (y)").spanned()).is_ok());
+/// 
+/// let errors = balanced_parens(Token::lexer("(λx. x").spanned()).unwrap_err();
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].span(), 0..1);
+/// assert!(matches!(errors[0].reason(), SimpleReason::Custom(message) if message == "unclosed parenthesis"));
+/// 
+/// let errors = balanced_parens(Token::lexer("x) (y").spanned()).unwrap_err();
+/// assert_eq!(errors.iter().map(|error| error.span()).collect::<Vec<_>>(), vec![1..2, 3..4]);
+/// ```
+pub fn balanced_parens<'s>(tokens: impl IntoIterator<Item = (Token<'s>, Range<usize>)>) -> Result<(), Vec<Simple<Token<'s>>>> {
+    let mut open = Vec::new();
+    let mut errors = Vec::new();
+    for (token, span) in tokens {
+        match token {
+            Token::OpenParens => open.push(span),
+            Token::CloseParens => match open.pop() {
+                Some(_) => (),
+                None => errors.push(Simple::custom(span, "unexpected closing parenthesis")),
+            },
+            _ => (),
+        }
+    }
+    errors.extend(open.into_iter().map(|span| Simple::custom(span, "unclosed parenthesis")));
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        errors.sort_by_key(|error| error.span().start);
+        Err(errors)
+    }
+}
+
 /// Parses a sequence of [Token]s into a [Statement], ignoring all whitespace and line comments after it.
+/// 
+/// # Examples
+/// 
+/// Imports consist of the path to a file in double quotes, optionally followed by a prefix.
+/// 
+/// ```
+/// use lamb::repl::*;
+/// 
+/// let Ok(Command::Exec(statements)) = Command::try_from("import \"numerals.lamb\" as nat; import \"bools.lamb\";") else { unreachable!() };
+/// assert_eq!(statements, vec![
+///     Statement::Import("numerals.lamb".to_string(), Some("nat".to_string())),
+///     Statement::Import("bools.lamb".to_string(), None),
+/// ]);
+/// ```
 pub fn statement_parser<'s>() -> impl Parser<Token<'s>, Statement<String>, Error = Simple<Token<'s>>> {
+    let path = select! {
+        Token::Path(path) => path.to_string(),
+    };
+    let import = just(Token::Ident("import")).ignore_then(filler_parser())
+        .ignore_then(path.then_ignore(filler_parser()))
+        .then(just(Token::Ident("as")).ignore_then(filler_parser())
+            .ignore_then(ident_parser())
+            .or_not())
+        .then_ignore(just(Token::Semicolon).then_ignore(filler_parser()))
+        .map(|(path, prefix)| Statement::Import(path, prefix));
+
+    let bind = bind_parser().map(|(name, term)| Statement::Bind(name, term));
+
+    import.or(bind)
+}
+
+/// Parses the contents of a file imported by a [Statement::Import] into its bindings, ignoring all whitespace and line comments around them.
+/// 
+/// Imported files may only contain bindings, and not imports of other files.
+pub fn bindings_parser<'s>() -> impl Parser<Token<'s>, Vec<(String, Term<String>)>, Error = Simple<Token<'s>>> {
+    filler_parser().ignore_then(bind_parser().repeated())
+        .then_ignore(end())
+}
+
+fn bind_parser<'s>() -> impl Parser<Token<'s>, (String, Term<String>), Error = Simple<Token<'s>>> {
     ident_parser().then_ignore(just(Token::Equals).then_ignore(filler_parser()))
         .then(term_parser())
         .then_ignore(just(Token::Semicolon).then_ignore(filler_parser()))
-        .map(|(name, term)| Statement::Bind(name, term))
 }
 
 /// Parses a sequence of [Token]s into a [Term], ignoring all whitespace and line comments after it.
+/// 
+/// # Examples
+/// 
+/// Since backslashes (`\`) are accepted as lambda symbols, [Term::to_ascii] produces output that can be parsed back into an identical [Term].
+/// 
+/// ```
+/// use chumsky::Parser;
+/// use chumsky::Stream;
+/// 
+/// use logos::Logos;
+/// 
+/// use lamb::*;
+/// use lamb::repl::lexer::Token;
+/// use lamb::repl::parser::term_parser;
+/// 
+/// let term = lambda_str!(λ f. (λ x. f (x x)) (λ x. f (x x)));
+/// let source = term.to_ascii();
+/// let parsed = term_parser()
+///     .parse(Stream::from_iter(source.len()..source.len(), Token::lexer(&source).spanned()))
+///     .unwrap();
+/// assert_eq!(parsed, term);
+/// ```
 pub fn term_parser<'s>() -> impl Parser<Token<'s>, Term<String>, Error = Simple<Token<'s>>> {
-    recursive(|term| {
-        let var = ident_parser().map(Term::var);
+    term_parser_with::<_, Unspanned, _, _>(spanned_var_parser(), spanned_param_parser())
+}
+
+/// Parses a sequence of [Token]s into a [Term] like [term_parser], but fails gracefully if the [Term] is nested too deeply.
+/// 
+/// The nesting depth is the number of parentheses and abstractions that enclose a [Token].
+/// Since [term_parser] is recursive, parsing extremely deeply nested input can overflow the stack, so the depth of every remaining [Token] is checked beforehand.
+/// If it exceeds the maximum depth, parsing stops with an error pointing at the first [Token] that is nested too deeply, before [term_parser] is ever run.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chumsky::Parser;
+/// use chumsky::Stream;
+/// use chumsky::error::SimpleReason;
+/// 
+/// use logos::Logos;
+/// 
+/// use lamb::*;
+/// use lamb::repl::lexer::Token;
+/// use lamb::repl::parser::term_parser_bounded;
+/// 
+/// let source = "(λx. (x x)) y";
+/// let term = term_parser_bounded(3)
+///     .parse(Stream::from_iter(source.len()..source.len(), Token::lexer(source).spanned()))
+///     .unwrap();
+/// assert_eq!(term, lambda_str!((λ x. x x) y));
+/// 
+/// let source = format!("{}x{}", "(".repeat(100_000), ")".repeat(100_000));
+/// let errors = term_parser_bounded(100)
+///     .parse(Stream::from_iter(source.len()..source.len(), Token::lexer(&source).spanned()))
+///     .unwrap_err();
+/// assert_eq!(errors[0].span(), 100..101);
+/// assert!(matches!(errors[0].reason(), SimpleReason::Custom(message) if message == "nesting too deep"));
+/// ```
+pub fn term_parser_bounded<'s>(max_depth: usize) -> impl Parser<Token<'s>, Term<String>, Error = Simple<Token<'s>>> {
+    nesting_parser(max_depth).rewind()
+        .ignore_then(term_parser())
+}
 
-        let abs = just(Token::Lambda).then_ignore(filler_parser())
-            .ignore_then(ident_parser().repeated())
-            .then_ignore(just(Token::Dot)
-                .then_ignore(filler_parser()))
-            .then(term.clone())
-            .foldr(Term::abs);
+/// Parses a sequence of [Token]s into a [Term] whose identifiers are paired with their spans in the source, ignoring all whitespace and line comments after it.
+/// 
+/// The span of an identifier only covers the identifier itself, and not any whitespace or line comments after it.
+/// This makes it possible to point at the exact location of a variable or formal parameter, such as when reporting errors.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chumsky::Parser;
+/// use chumsky::Stream;
+/// 
+/// use logos::Logos;
+/// 
+/// use lamb::*;
+/// use lamb::repl::lexer::Token;
+/// use lamb::repl::parser::term_parser_spanned;
+/// 
+/// let source = "x y";
+/// let term = term_parser_spanned()
+///     .parse(Stream::from_iter(source.len()..source.len(), Token::lexer(source).spanned()))
+///     .unwrap();
+/// assert_eq!(term, Term::app(
+///     Term::var(("x".to_string(), 0..1)),
+///     Term::var(("y".to_string(), 2..3)),
+/// ));
+/// ```
+pub fn term_parser_spanned<'s>() -> impl Parser<Token<'s>, Term<(String, Range<usize>)>, Error = Simple<Token<'s>>> {
+    term_parser_with::<_, Unspanned, _, _>(spanned_var_parser().map(with_span), spanned_param_parser().map(with_span))
+}
 
-        let parens = term.clone()
-            .delimited_by(just(Token::OpenParens).then_ignore(filler_parser()), just(Token::CloseParens).then_ignore(filler_parser()));
+/// The spans in the source of every subterm of a [Term], keyed by the path to the subterm (see [Direction]).
+/// 
+/// [TermSpans] are produced alongside [Term]s by [term_parser_with_spans], which keeps the [Term]'s identifiers unchanged (unlike [term_parser_spanned]).
+/// 
+/// The span of a variable covers its identifier, and the span of an abstraction covers everything from its lambda symbol (or formal parameter, for abstractions introduced by listing multiple formal parameters) to the end of its body.
+/// The span of an application covers everything from the start of its function to the end of its argument.
+/// Subterms enclosed in parentheses have spans that include the parentheses.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TermSpans {
+    spans: HashMap<Vec<Direction>, Range<usize>>,
+}
 
-        let app = parens.clone()
-            .or(var.clone())
-            .then(choice((abs.clone(), var.clone(), parens.clone())).repeated()
-                .at_least(1))
-            .foldl(Term::app);
+impl TermSpans {
+    /// Returns the span of the subterm at the specified path, or [None] if there is no subterm at that path.
+    pub fn get(&self, path: &[Direction]) -> Option<&Range<usize>> {
+        self.spans.get(path)
+    }
 
-        choice((abs, app, var, parens))
-    })
+    // Paths are built top-down by extending the path to the parent, rather than prepending to the paths of every descendant
+    fn insert(&mut self, tree: SpanTree, path: &mut Vec<Direction>) {
+        for (direction, child) in tree.children {
+            path.push(direction);
+            self.insert(child, path);
+            path.pop();
+        }
+        self.spans.insert(path.clone(), tree.span);
+    }
 }
 
-/// Maps a [Token::Ident] to a [String], ignoring all whitespace and line comments after it.
+impl From<SpanTree> for TermSpans {
+    fn from(tree: SpanTree) -> Self {
+        let mut spans = Self::default();
+        spans.insert(tree, &mut Vec::new());
+        spans
+    }
+}
+
+/// Parses a sequence of [Token]s into a [Term] along with the [TermSpans] of its subterms, ignoring all whitespace and line comments after it.
+/// 
+/// This accepts exactly the same syntax as [term_parser], and produces the same [Term].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chumsky::Parser;
+/// use chumsky::Stream;
+/// 
+/// use logos::Logos;
+/// 
+/// use lamb::*;
+/// use lamb::repl::lexer::Token;
+/// use lamb::repl::parser::term_parser_with_spans;
+/// 
+/// let source = "x y";
+/// let (term, spans) = term_parser_with_spans()
+///     .parse(Stream::from_iter(source.len()..source.len(), Token::lexer(source).spanned()))
+///     .unwrap();
+/// assert_eq!(term, lambda_str!(x y));
+/// assert_eq!(spans.get(&[]), Some(&(0..3)));
+/// assert_eq!(spans.get(&[Direction::Func]), Some(&(0..1)));
+/// assert_eq!(spans.get(&[Direction::Arg]), Some(&(2..3)));
+/// 
+/// let source = "λf x. f (x  x) # comment";
+/// let (_, spans) = term_parser_with_spans()
+///     .parse(Stream::from_iter(source.len()..source.len(), Token::lexer(source).spanned()))
+///     .unwrap();
+/// assert_eq!(spans.get(&[]).map(|span| &source[span.clone()]), Some("λf x. f (x  x)"));
+/// assert_eq!(spans.get(&[Direction::Body]).map(|span| &source[span.clone()]), Some("x. f (x  x)"));
+/// assert_eq!(spans.get(&[Direction::Body, Direction::Body, Direction::Arg]).map(|span| &source[span.clone()]), Some("(x  x)"));
+/// assert_eq!(spans.get(&[Direction::Func]), None);
+/// ```
+pub fn term_parser_with_spans<'s>() -> impl Parser<Token<'s>, (Term<String>, TermSpans), Error = Simple<Token<'s>>> {
+    term_parser_with::<_, Spanned, _, _>(spanned_var_parser(), spanned_param_parser())
+        .map(|(term, tree)| (term, TermSpans::from(tree)))
+}
+
+// The shared grammar behind all the term parsers, which hands each parsed piece of a Term to a TermBuilder
+fn term_parser_with<'s, I, B, P, Q>(ident: P, param: Q) -> impl Parser<Token<'s>, B::Node, Error = Simple<Token<'s>>>
+where
+    I: Clone + 's,
+    B: TermBuilder<I> + 's,
+    B::Node: 's,
+    P: Parser<Token<'s>, (I, Range<usize>), Error = Simple<Token<'s>>> + Clone + 's,
+    Q: Parser<Token<'s>, (I, Range<usize>), Error = Simple<Token<'s>>> + Clone + 's, {
+        recursive(|term| {
+            let var = ident.clone().map(|(var, span)| B::var(var, span));
+
+            let abs = just(Token::Lambda).map_with_span(|_, span: Range<usize>| span.start)
+                .then_ignore(filler_parser())
+                .then(param.repeated())
+                .then_ignore(just(Token::Dot)
+                    .then_ignore(filler_parser()))
+                .then(term.clone())
+                .map(|((start, params), body)| {
+                    params.into_iter()
+                        .enumerate()
+                        .rev()
+                        .fold(body, |body, (index, (param, span))| {
+                            // Only the outermost abstraction starts at the lambda symbol
+                            let start = if index == 0 { start } else { span.start };
+                            B::abs(param, start, body)
+                        })
+                });
+
+            let parens = just(Token::OpenParens).map_with_span(|_, span: Range<usize>| span.start)
+                .then_ignore(filler_parser())
+                .then(term.clone())
+                .then(just(Token::CloseParens).map_with_span(|_, span: Range<usize>| span.end))
+                .then_ignore(filler_parser())
+                .map(|((start, term), end)| B::parens(term, start..end));
+
+            let app = parens.clone()
+                .or(var.clone())
+                .then(choice((abs.clone(), var.clone(), parens.clone())).repeated()
+                    .at_least(1))
+                .foldl(B::app);
+
+            choice((abs, app, var, parens))
+        })
+    }
+
+// Assembles the nodes produced by term_parser_with from its identifiers and their spans
+trait TermBuilder<I> {
+    type Node: Clone;
+
+    fn var(var: I, span: Range<usize>) -> Self::Node;
+
+    fn abs(param: I, start: usize, body: Self::Node) -> Self::Node;
+
+    fn app(func: Self::Node, arg: Self::Node) -> Self::Node;
+
+    fn parens(node: Self::Node, span: Range<usize>) -> Self::Node;
+}
+
+// Builds plain Terms, discarding all spans
+struct Unspanned;
+
+impl<I: Clone> TermBuilder<I> for Unspanned {
+    type Node = Term<I>;
+
+    fn var(var: I, _: Range<usize>) -> Self::Node {
+        Term::var(var)
+    }
+
+    fn abs(param: I, _: usize, body: Self::Node) -> Self::Node {
+        Term::abs(param, body)
+    }
+
+    fn app(func: Self::Node, arg: Self::Node) -> Self::Node {
+        Term::app(func, arg)
+    }
+
+    fn parens(node: Self::Node, _: Range<usize>) -> Self::Node {
+        node
+    }
+}
+
+// Builds Terms along with a tree of the spans of their subterms, which is later flattened into TermSpans
+struct Spanned;
+
+impl TermBuilder<String> for Spanned {
+    type Node = (Term<String>, SpanTree);
+
+    fn var(var: String, span: Range<usize>) -> Self::Node {
+        (Term::var(var), SpanTree::new(span, Vec::new()))
+    }
+
+    fn abs(param: String, start: usize, (body, body_spans): Self::Node) -> Self::Node {
+        let span = start..body_spans.span.end;
+        (Term::abs(param, body), SpanTree::new(span, vec![(Direction::Body, body_spans)]))
+    }
+
+    fn app((func, func_spans): Self::Node, (arg, arg_spans): Self::Node) -> Self::Node {
+        let span = func_spans.span.start..arg_spans.span.end;
+        (Term::app(func, arg), SpanTree::new(span, vec![(Direction::Func, func_spans), (Direction::Arg, arg_spans)]))
+    }
+
+    fn parens((term, mut spans): Self::Node, span: Range<usize>) -> Self::Node {
+        spans.span = span;
+        (term, spans)
+    }
+}
+
+#[derive(Clone)]
+struct SpanTree {
+    span: Range<usize>,
+    children: Vec<(Direction, SpanTree)>,
+}
+
+impl SpanTree {
+    fn new(span: Range<usize>, children: Vec<(Direction, SpanTree)>) -> Self {
+        Self {
+            span,
+            children,
+        }
+    }
+}
+
+// Pairs an identifier with its span, for parsers whose Terms keep the spans of their identifiers
+fn with_span<I>((ident, span): (I, Range<usize>)) -> ((I, Range<usize>), Range<usize>) {
+    ((ident, span.clone()), span)
+}
+
+// Consumes every remaining token, failing if any of them is nested more deeply than the maximum depth
+fn nesting_parser<'s>(max_depth: usize) -> impl Parser<Token<'s>, (), Error = Simple<Token<'s>>> {
+    any().map_with_span(|token, span| (token, span))
+        .repeated()
+        .try_map(move |tokens: Vec<(Token<'s>, Range<usize>)>, _| {
+            // Each entry is the number of abstractions opened inside a pair of parentheses, which end along with it
+            let mut groups = vec![0];
+            let mut depth = 0;
+            for (token, span) in tokens {
+                match token {
+                    Token::OpenParens => {
+                        groups.push(0);
+                        depth += 1;
+                    },
+                    Token::Lambda => {
+                        *groups.last_mut().unwrap() += 1;
+                        depth += 1;
+                    },
+                    Token::CloseParens if groups.len() > 1 => depth -= 1 + groups.pop().unwrap(),
+                    _ => (),
+                }
+                if depth > max_depth {
+                    return Err(Simple::custom(span, "nesting too deep"));
+                }
+            }
+            Ok(())
+        })
+}
+
+/// Maps a [Token::Ident] or [Token::UpperIdent] to a [String], ignoring all whitespace and line comments after it.
 pub fn ident_parser<'s>() -> impl Parser<Token<'s>, String, Error = Simple<Token<'s>>> + Clone {
     let ident = select! {
         Token::Ident(ident) => ident.to_string(),
+        Token::UpperIdent(ident) => ident.to_string(),
     };
     ident.then_ignore(filler_parser())
         .labelled("identifier")
 }
 
+/// Maps a [Token::Ident] or [Token::UpperIdent] to a [String] paired with its span, ignoring all whitespace and line comments after it.
+pub fn spanned_ident_parser<'s>() -> impl Parser<Token<'s>, (String, Range<usize>), Error = Simple<Token<'s>>> + Clone {
+    let ident = select! {
+        Token::Ident(ident) => ident.to_string(),
+        Token::UpperIdent(ident) => ident.to_string(),
+    };
+    ident.map_with_span(|ident, span| (ident, span))
+        .then_ignore(filler_parser())
+        .labelled("identifier")
+}
+
+/// Maps a [Token::Ident] or [Token::UpperIdent], optionally qualified by more of them separated by [Token::Dot]s (such as `nat.succ`), to a [String] paired with its span, ignoring all whitespace and line comments after it.
+/// 
+/// This accepts everything that can be a variable, including the qualified names of bindings imported with a prefix (see [Statement::Import]).
+/// The parts of a qualified name cannot be separated by whitespace, and formal parameters cannot be qualified, so `λx.x` is still an abstraction.
+pub fn spanned_var_parser<'s>() -> impl Parser<Token<'s>, (String, Range<usize>), Error = Simple<Token<'s>>> + Clone {
+    let ident = select! {
+        Token::Ident(ident) => ident,
+        Token::UpperIdent(ident) => ident,
+    };
+    ident.then(just(Token::Dot).ignore_then(ident).repeated())
+        .map(|(first, rest)| rest.into_iter().fold(first.to_string(), |name, part| format!("{}.{}", name, part)))
+        .map_with_span(|name, span| (name, span))
+        .then_ignore(filler_parser())
+        .labelled("identifier")
+}
+
+/// Maps a [Token::Ident], [Token::UpperIdent], or [Token::Underscore] to a [String], ignoring all whitespace and line comments after it.
+/// 
+/// This accepts everything that can be the formal parameter of an abstraction.
+pub fn param_parser<'s>() -> impl Parser<Token<'s>, String, Error = Simple<Token<'s>>> + Clone {
+    let underscore = just(Token::Underscore).to("_".to_string())
+        .then_ignore(filler_parser())
+        .labelled("identifier");
+    ident_parser().or(underscore)
+}
+
+/// Maps a [Token::Ident], [Token::UpperIdent], or [Token::Underscore] to a [String] paired with its span, ignoring all whitespace and line comments after it.
+/// 
+/// This accepts everything that can be the formal parameter of an abstraction.
+pub fn spanned_param_parser<'s>() -> impl Parser<Token<'s>, (String, Range<usize>), Error = Simple<Token<'s>>> + Clone {
+    let underscore = just(Token::Underscore).map_with_span(|_, span| ("_".to_string(), span))
+        .then_ignore(filler_parser())
+        .labelled("identifier");
+    spanned_ident_parser().or(underscore)
+}
+
 /// Maps a [Token::Number] to a [usize], ignoring all whitespace and line comments after it.
 pub fn number_parser<'s>() -> impl Parser<Token<'s>, usize, Error = Simple<Token<'s>>> + Clone {
     let number = select! {
@@ -11,12 +11,19 @@ use crate::Term;
 pub enum Statement<T> {
     /// A binding of a [Term] to an identifier.
     Bind(T, Term<T>),
+    /// An import of all the bindings in the file at a path, optionally qualifying each of their names with a prefix.
+    /// 
+    /// With a prefix such as `nat`, a binding named `succ` in the file is imported as `nat.succ`.
+    /// References between the file's own bindings are qualified in the same way, so they keep referring to each other rather than to any unqualified bindings that already exist.
+    Import(String, Option<String>),
 }
 
 impl<T: Display> Display for Statement<T> {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
         match self {
             Self::Bind(name, term) => write!(formatter, "{} = {};", name, term),
+            Self::Import(path, None) => write!(formatter, "import \"{}\";", path),
+            Self::Import(path, Some(prefix)) => write!(formatter, "import \"{}\" as {};", path, prefix),
         }
     }
 }
\ No newline at end of file
@@ -4,9 +4,61 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 
+pub mod analysis;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+pub mod arena;
+pub use arena::*;
+
+pub mod church;
+pub use church::*;
+
+pub mod closure;
+pub use closure::*;
+
+pub mod combinatory;
+pub use combinatory::*;
+
+pub mod eta;
+
+pub mod fresh;
+pub use fresh::*;
+
+pub mod json;
+pub use json::*;
+
+pub mod linear;
+pub use linear::*;
+
+pub mod notation;
+pub use notation::*;
+
+#[cfg(feature = "ops")]
+mod ops;
+
+pub mod ord;
+pub use ord::*;
+
+pub mod partial;
+
+pub mod redex;
+
 pub mod reduce;
 pub use reduce::*;
 
+pub mod rename;
+
+pub mod shared;
+pub use shared::*;
+
+pub mod template;
+pub use template::*;
+
+pub mod traverse;
+pub use traverse::*;
+
 /// A lambda calculus term, which is either a variable, an abstraction, or an application.
 /// 
 /// [Term]s can be constructed in multiple ways:
@@ -17,6 +69,9 @@ pub use reduce::*;
 /// 
 /// The macros offer some syntactic sugar for the construction of [Term]s, and will suffice for the vast majority of cases.
 /// For greater control over how [Term]s are constructed, consider using the associated functions and enum variants.
+/// 
+/// Dropping a [Term] recurses once for every level of nesting, so very deep [Term]s should be dropped using [Term::dispose] instead.
+/// [Term] does not implement [Drop] itself, since that would prevent moving subterms out of its variants, and detaching a subterm without recursion would require a placeholder [Term] that an arbitrary `T` cannot provide.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Term<T> {
     /// A variable, which may be either free or bound to an abstraction's formal parameter.
@@ -39,21 +94,271 @@ impl<T> Term<T> {
     }
 
     /// Constructs an application, applying the [Term] on the left (`func`) to that on the right (`arg`).
+    /// 
+    /// Every application has exactly one function and one argument, so applying a [Term] to multiple arguments results in a left-nested chain of applications.
+    /// This is the shape produced by the [app!](crate::app) and [lambda!](crate::lambda) macros, and by [Term::apply_all].
+    /// A right-nested chain is not an alternative shape for the same [Term], but a different [Term] altogether, where an argument is itself an application.
+    /// Consequently, there is no need to re-associate applications - each possible nesting already has a distinct meaning, which is reflected by the [Display] `impl`ementation.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let left = Term::app(Term::app(var!(a), var!(b)), var!(c));
+    /// assert_eq!(left, app!(var!(a), var!(b), var!(c)));
+    /// assert_eq!(left.to_string(), "a b c");
+    /// 
+    /// let right = Term::app(var!(a), Term::app(var!(b), var!(c)));
+    /// assert_eq!(right, lambda!(a (b c)));
+    /// assert_eq!(right.to_string(), "a (b c)");
+    /// 
+    /// assert_ne!(left, right);
+    /// ```
     pub fn app(func: Self, arg: Self) -> Self {
         Self::App(Box::new(func), Box::new(arg))
     }
+
+    /// Constructs an application, applying the [Term] to the argument (`arg`).
+    /// 
+    /// This is equivalent to [Term::app], but allows chaining applications fluently.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let id = lambda!(λ x. x);
+    /// assert_eq!(id.clone().apply(var!(y)), app!(id, var!(y)));
+    /// ```
+    pub fn apply(self, arg: Self) -> Self {
+        Self::app(self, arg)
+    }
+
+    /// Constructs a left-associative chain of applications, applying the [Term] to each argument (`args`) in turn.
+    /// 
+    /// If there are no arguments, the [Term] is returned as-is.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let constant = lambda!(λ x y. x);
+    /// assert_eq!(constant.clone().apply_all([var!(a), var!(b)]), app!(constant.clone(), var!(a), var!(b)));
+    /// assert_eq!(constant.clone().apply_all([]), constant);
+    /// ```
+    pub fn apply_all(self, args: impl IntoIterator<Item = Self>) -> Self {
+        args.into_iter().fold(self, Self::app)
+    }
+
+    /// Returns the head of the [Term]'s application spine, along with the arguments it is applied to from left to right.
+    /// 
+    /// This is the reverse of [Term::apply_all] - the head is the innermost function of a left-nested chain of applications, and is never itself an application.
+    /// A [Term] that is not an application is its own head, with no arguments.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!(f a (b c));
+    /// assert_eq!(term.spine(), (&var!(f), vec![&var!(a), &lambda!(b c)]));
+    /// 
+    /// let term = lambda!(λ x. x);
+    /// assert_eq!(term.spine(), (&term, vec![]));
+    /// ```
+    pub fn spine(&self) -> (&Self, Vec<&Self>) {
+        match self {
+            Self::App(func, arg) => {
+                let (head, mut args) = func.spine();
+                args.push(arg);
+                (head, args)
+            },
+            head => (head, Vec::new()),
+        }
+    }
+
+    /// Returns mutable references to the head of the [Term]'s application spine and the arguments it is applied to (see [Term::spine]).
+    /// 
+    /// This allows the head and arguments to be modified in-place, such as to β-reduce every argument.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let mut term = lambda!(f a b);
+    /// let (head, args) = term.spine_mut();
+    /// *head = var!(g);
+    /// for arg in args {
+    ///     *arg = app!(var!(h), arg.clone());
+    /// }
+    /// assert_eq!(term, lambda!(g (h a) (h b)));
+    /// ```
+    pub fn spine_mut(&mut self) -> (&mut Self, Vec<&mut Self>) {
+        match self {
+            Self::App(func, arg) => {
+                let (head, mut args) = func.spine_mut();
+                args.push(arg);
+                (head, args)
+            },
+            head => (head, Vec::new()),
+        }
+    }
+
+    /// Returns the formal parameters of the [Term]'s leading abstractions from outermost to innermost, along with the body of the innermost one.
+    /// 
+    /// Peeling stops at the first subterm that is not an abstraction, so abstractions nested inside applications are left alone.
+    /// A [Term] that is not an abstraction is returned as-is, with no formal parameters.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let fix_strict = lambda!(λ f. (λ x. f (λ y. x x y)) (λ x. f (λ y. x x y)));
+    /// assert_eq!(fix_strict.peel_abstractions(), (vec![&"f"], &lambda!((λ x. f (λ y. x x y)) (λ x. f (λ y. x x y)))));
+    /// 
+    /// let constant = lambda!(λ x y. x);
+    /// assert_eq!(constant.peel_abstractions(), (vec![&"x", &"y"], &var!(x)));
+    /// ```
+    pub fn peel_abstractions(&self) -> (Vec<&T>, &Self) {
+        let mut params = Vec::new();
+        let mut body = self;
+        while let Self::Abs(param, inner) = body {
+            params.push(param);
+            body = inner;
+        }
+        (params, body)
+    }
+
+    /// Drops the [Term] iteratively instead of recursively.
+    /// 
+    /// Dropping a [Term] normally recurses once for every level of nesting, which can overflow the stack for very deep [Term]s (such as large Church-encoded numbers built using [Term::numeral]).
+    /// This function instead moves subterms onto a heap-allocated stack before dropping them, so the call stack it uses does not depend on the [Term]'s height.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let mut term = var!(x);
+    /// for _ in 0..200_000 {
+    ///     term = Term::abs("y", term);
+    /// }
+    /// term.dispose();
+    /// ```
+    pub fn dispose(self) {
+        let mut stack = vec![self];
+        while let Some(term) = stack.pop() {
+            match term {
+                Self::Var(_) => (),
+                Self::Abs(_, body) => stack.push(*body),
+                Self::App(func, arg) => {
+                    stack.push(*func);
+                    stack.push(*arg);
+                },
+            }
+        }
+    }
+}
+
+impl<T: Display> Term<T> {
+    /// Returns the [Term] formatted like its [Display] `impl`ementation, but with a backslash (`\`) in place of each lambda symbol (`λ`).
+    /// 
+    /// Since the backslash is also accepted as a lambda symbol when parsing, the result can always be parsed back into an identical [Term] as long as its identifiers are ASCII.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// assert_eq!(lambda!(λ x. x).to_ascii(), "\\x. x");
+    /// assert_eq!(lambda!((λ x. x x) (λ x. x x)).to_ascii(), "(\\x. x x) (\\x. x x)");
+    /// ```
+    pub fn to_ascii(&self) -> String {
+        Notation {
+            term: self,
+            lambda: "\\",
+        }.to_string()
+    }
 }
 
+/// Displays the [Term] in the same syntax accepted by the [lambda!](crate::lambda) macro, with as few parentheses as possible.
+/// 
+/// With the alternate flag (`{:#}`), the [Term] is instead displayed as an indented tree, with one node per line.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// 
+/// let term = lambda!((λ x. x) y);
+/// assert_eq!(format!("{}", term), "(λx. x) y");
+/// assert_eq!(format!("{:#}", term), "App\n  Abs x\n    Var x\n  Var y");
+/// ```
 impl<T: Display> Display for Term<T> {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        if formatter.alternate() {
+            self.fmt_tree(formatter, 0)
+        } else {
+            Notation {
+                term: self,
+                lambda: "λ",
+            }.fmt(formatter)
+        }
+    }
+}
+
+impl<T: Display> Term<T> {
+    fn fmt_tree(&self, formatter: &mut Formatter<'_>, depth: usize) -> FmtResult {
+        if depth > 0 {
+            writeln!(formatter)?;
+        }
+        write!(formatter, "{:indent$}", "", indent = depth * 2)?;
         match self {
-            Self::Var(var) => write!(formatter, "{}", var),
-            Self::Abs(param, body) => write!(formatter, "λ{}. {}", param, body),
-            Self::App(func, arg) => match (func.as_ref(), arg.as_ref()) {
-                (Self::Abs(_, _), Self::Abs(_, _) | Self::App(_, _)) => write!(formatter, "({}) ({})", func, arg),
-                (Self::Abs(_, _), _) => write!(formatter, "({}) {}", func, arg),
-                (_, Self::Abs(_, _) | Self::App(_, _)) => write!(formatter, "{} ({})", func, arg),
-                _ => write!(formatter, "{} {}", func, arg),
+            Self::Var(var) => write!(formatter, "Var {}", var),
+            Self::Abs(param, body) => {
+                write!(formatter, "Abs {}", param)?;
+                body.fmt_tree(formatter, depth + 1)
+            },
+            Self::App(func, arg) => {
+                write!(formatter, "App")?;
+                func.fmt_tree(formatter, depth + 1)?;
+                arg.fmt_tree(formatter, depth + 1)
+            },
+        }
+    }
+}
+
+struct Notation<'t, T> {
+    term: &'t Term<T>,
+    lambda: &'static str,
+}
+
+impl<'t, T> Notation<'t, T> {
+    fn with(&self, term: &'t Term<T>) -> Self {
+        Self {
+            term,
+            lambda: self.lambda,
+        }
+    }
+}
+
+impl<T: Display> Display for Notation<'_, T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self.term {
+            Term::Var(var) => write!(formatter, "{}", var),
+            Term::Abs(param, body) => write!(formatter, "{}{}. {}", self.lambda, param, self.with(body)),
+            Term::App(func, arg) => {
+                let (func, arg) = (func.as_ref(), arg.as_ref());
+                match (func, arg) {
+                    (Term::Abs(_, _), Term::Abs(_, _) | Term::App(_, _)) => write!(formatter, "({}) ({})", self.with(func), self.with(arg)),
+                    (Term::Abs(_, _), _) => write!(formatter, "({}) {}", self.with(func), self.with(arg)),
+                    (_, Term::Abs(_, _) | Term::App(_, _)) => write!(formatter, "{} ({})", self.with(func), self.with(arg)),
+                    _ => write!(formatter, "{} {}", self.with(func), self.with(arg)),
+                }
             },
         }
     }
@@ -244,4 +549,56 @@ macro_rules! lambda {
     (~internal $($args: tt)+) => {
         std::iter::once($crate::lambda!($($args)+))
     };
-}
\ No newline at end of file
+}
+
+/// Constructs a [Term] with [String] identifiers using standard untyped lambda calculus notation.
+/// 
+/// This macro accepts exactly the same syntax as [lambda!](crate::lambda), but produces a `Term<String>` rather than a `Term<&'static str>`.
+/// This makes it a better fit for [Term]s that will interact with the REPL or other APIs working with owned identifiers.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// 
+/// let term_a = lambda_str!(λ x. x);
+/// assert_eq!(term_a, Term::abs("x".to_string(), Term::var("x".to_string())));
+/// 
+/// let term_b = lambda_str!((λ x. x) y);
+/// assert_eq!(term_b, Term::app(
+///     Term::abs("x".to_string(), Term::var("x".to_string())),
+///     Term::var("y".to_string()),
+/// ));
+/// ```
+#[macro_export]
+macro_rules! lambda_str {
+    (λ $param: ident $($params: ident)+. $($body: tt)+) => {
+        $crate::Term::abs(stringify!($param).to_string(), $crate::lambda_str!(λ$($params)+. $($body)+))
+    };
+    (λ $param: ident. $($body: tt)+) => {
+        $crate::Term::abs(stringify!($param).to_string(), $crate::lambda_str!($($body)+))
+    };
+    ($func: ident $($args: tt)+) => {
+        $crate::lambda_str!(~internal $($args)+).into_iter()
+            .fold($crate::Term::var(stringify!($func).to_string()), $crate::Term::app)
+    };
+    (($($func: tt)+) $($args: tt)+) => {
+        $crate::lambda_str!(~internal $($args)+).into_iter()
+            .fold($crate::lambda_str!($($func)+), $crate::Term::app)
+    };
+    ($var: ident) => {
+        $crate::Term::var(stringify!($var).to_string())
+    };
+    (($($term: tt)+)) => {
+        $crate::lambda_str!($($term)+)
+    };
+    (~internal $func: ident $($args: tt)+) => {
+        std::iter::once($crate::Term::var(stringify!($func).to_string())).chain($crate::lambda_str!(~internal $($args)+))
+    };
+    (~internal ($($func: tt)+) $($args: tt)+) => {
+        std::iter::once($crate::lambda_str!($($func)+)).chain($crate::lambda_str!(~internal $($args)+))
+    };
+    (~internal $($args: tt)+) => {
+        std::iter::once($crate::lambda_str!($($args)+))
+    };
+}
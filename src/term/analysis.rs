@@ -0,0 +1,320 @@
+//! Static analysis of [Term]s, performed without β-reducing them.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::LocalNamelessTerm;
+use crate::OrdTerm;
+use crate::Term;
+use crate::TraversalOrder;
+
+impl<T> Term<T> {
+    /// Returns the number of abstractions in the [Term].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let compose = lambda!(λ f g x. f (g x));
+    /// assert_eq!(compose.count_abstractions(), 3);
+    /// assert_eq!(compose.count_applications(), 2);
+    /// assert_eq!(compose.count_variables(), 3);
+    /// ```
+    pub fn count_abstractions(&self) -> usize {
+        match self {
+            Self::Var(_) => 0,
+            Self::Abs(_, body) => 1 + body.count_abstractions(),
+            Self::App(func, arg) => func.count_abstractions() + arg.count_abstractions(),
+        }
+    }
+
+    /// Returns the number of applications in the [Term].
+    pub fn count_applications(&self) -> usize {
+        match self {
+            Self::Var(_) => 0,
+            Self::Abs(_, body) => body.count_applications(),
+            Self::App(func, arg) => 1 + func.count_applications() + arg.count_applications(),
+        }
+    }
+
+    /// Returns the number of variable occurrences in the [Term].
+    /// 
+    /// Formal parameters of abstractions are not counted, but every occurrence of a variable is, regardless of whether it is free or bound.
+    pub fn count_variables(&self) -> usize {
+        match self {
+            Self::Var(_) => 1,
+            Self::Abs(_, body) => body.count_variables(),
+            Self::App(func, arg) => func.count_variables() + arg.count_variables(),
+        }
+    }
+
+    /// Returns the number of nodes (variables, abstractions, and applications) in the [Term].
+    pub(crate) fn size(&self) -> usize {
+        match self {
+            Self::Var(_) => 1,
+            Self::Abs(_, body) => 1 + body.size(),
+            Self::App(func, arg) => 1 + func.size() + arg.size(),
+        }
+    }
+}
+
+impl<T: Eq> Term<T> {
+    /// Estimates the amount of work required to β-reduce the [Term], without actually β-reducing it.
+    /// 
+    /// Every β-redex `(λx. b) a` in the [Term] contributes `1 + n * s` to the estimate, where `n` is the number of free occurrences of `x` in `b` and `s` is the number of nodes in `a`.
+    /// This reflects both the cost of contracting the redex and the cost of copying its argument once for every use of the formal parameter.
+    /// 
+    /// This is purely a static heuristic - it does not account for redexes that are created or erased by β-reduction, and therefore says nothing about whether the [Term] terminates.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let arg = lambda!(λ a. a a a);
+    /// let duplicating = app!(lambda!(λ x. x x), arg.clone());
+    /// let discarding = app!(lambda!(λ x. y), arg);
+    /// assert!(duplicating.reduction_cost_estimate() > discarding.reduction_cost_estimate());
+    /// ```
+    pub fn reduction_cost_estimate(&self) -> usize {
+        match self {
+            Self::Var(_) => 0,
+            Self::Abs(_, body) => body.reduction_cost_estimate(),
+            Self::App(func, arg) => {
+                let redex_cost = match func.as_ref() {
+                    Self::Abs(param, body) => 1 + body.occurrences(param) * arg.size(),
+                    _ => 0,
+                };
+                redex_cost + func.reduction_cost_estimate() + arg.reduction_cost_estimate()
+            },
+        }
+    }
+
+    /// Estimates the maximum number of copies of a single argument that β-reducing the [Term] could create at once, without actually β-reducing it.
+    /// 
+    /// This is the largest number of times any abstraction's formal parameter occurs in its body, since contracting a β-redex with that abstraction copies its argument once for every occurrence.
+    /// A result of 1 or less means no argument is ever duplicated by the abstractions currently in the [Term], making it a good candidate for sharing-free β-reduction.
+    /// 
+    /// Like [Term::reduction_cost_estimate], this is purely a static heuristic - abstractions created by β-reduction are not accounted for.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let dup = lambda!(λ f x. f x x);
+    /// let id = lambda!(λ x. x);
+    /// assert_eq!(dup.count_max_live_substitutions(), 2);
+    /// assert_eq!(id.count_max_live_substitutions(), 1);
+    /// assert_eq!(lambda!(λ x. y).count_max_live_substitutions(), 0);
+    /// ```
+    pub fn count_max_live_substitutions(&self) -> usize {
+        match self {
+            Self::Var(_) => 0,
+            Self::Abs(param, body) => body.occurrences(param).max(body.count_max_live_substitutions()),
+            Self::App(func, arg) => func.count_max_live_substitutions().max(arg.count_max_live_substitutions()),
+        }
+    }
+
+    /// Returns the number of free occurrences of a variable in the [Term].
+    pub(crate) fn occurrences(&self, var: &T) -> usize {
+        match self {
+            Self::Var(other) => usize::from(other == var),
+            Self::Abs(param, _) if param == var => 0,
+            Self::Abs(_, body) => body.occurrences(var),
+            Self::App(func, arg) => func.occurrences(var) + arg.occurrences(var),
+        }
+    }
+}
+
+impl<T: Clone + Eq> Term<T> {
+    /// Checks whether any subterm of the [Term] (including the [Term] itself) is α-equivalent to another [Term].
+    /// 
+    /// Each subterm is compared in isolation, so variables bound outside a subterm are treated as free in it, and are compared with the free variables of the other [Term] by their identifiers.
+    /// This mirrors how [Term::common_subterms] compares subterms.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!(λ x. f (g x));
+    /// assert!(term.contains_subterm(&lambda!(g x)));
+    /// assert!(term.contains_subterm(&lambda!(λ y. f (g y))));
+    /// assert!(!term.contains_subterm(&lambda!(h x)));
+    /// assert!(!term.contains_subterm(&lambda!(g y)));
+    /// ```
+    pub fn contains_subterm(&self, needle: &Term<T>) -> bool {
+        let size = needle.size();
+        let needle = LocalNamelessTerm::from(needle);
+        self.traverse(TraversalOrder::PreOrder)
+            .filter(|subterm| subterm.size() == size)
+            .any(|subterm| LocalNamelessTerm::from(subterm).nameless_eq(&needle))
+    }
+
+    /// Partitions a slice of [Term]s into groups of α-equivalent [Term]s, returning the indices of the [Term]s in each group.
+    /// 
+    /// Groups are ordered by their first [Term], and the indices within each group are in ascending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let terms = [lambda!(λ x. x), lambda!(λ x. z), lambda!(λ y. y)];
+    /// assert_eq!(Term::partition_by_alpha(&terms), vec![vec![0, 2], vec![1]]);
+    /// ```
+    pub fn partition_by_alpha(terms: &[Term<T>]) -> Vec<Vec<usize>> {
+        let mut groups = Vec::<(LocalNamelessTerm<T>, Vec<usize>)>::new();
+        for (index, term) in terms.iter().enumerate() {
+            let local_nameless = LocalNamelessTerm::from(term);
+            match groups.iter_mut().find(|(representative, _)| representative.nameless_eq(&local_nameless)) {
+                Some((_, indices)) => indices.push(index),
+                None => groups.push((local_nameless, vec![index])),
+            }
+        }
+        groups.into_iter()
+            .map(|(_, indices)| indices)
+            .collect()
+    }
+}
+
+impl<T: Clone + Ord> Term<T> {
+    /// Returns the subterms of at least a certain number of nodes that occur more than once in the [Term] up to α-equivalence, along with how many times they occur.
+    /// 
+    /// The subterms are returned in the order of their first occurrence (in pre-order), and each is returned as it first occurs.
+    /// Subterms nested inside repeated subterms are counted once for every occurrence of the latter, and may therefore be returned as well.
+    /// 
+    /// Free variables of a subterm (including those bound outside it) are compared by their identifiers, while variables bound inside it are compared by position.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!(f (λ x. x) (g (λ y. y)));
+    /// assert_eq!(term.common_subterms(2), vec![(lambda!(λ x. x), 2)]);
+    /// assert_eq!(term.common_subterms(3), vec![]);
+    /// ```
+    pub fn common_subterms(&self, min_size: usize) -> Vec<(Term<T>, usize)> {
+        let mut occurrences = BTreeMap::new();
+        for (index, subterm) in self.traverse(TraversalOrder::PreOrder).enumerate() {
+            if subterm.size() >= min_size {
+                occurrences.entry(OrdTerm(subterm.clone()))
+                    .or_insert((index, 0))
+                    .1 += 1;
+            }
+        }
+        let mut common = occurrences.into_iter()
+            .filter(|(_, (_, count))| *count > 1)
+            .map(|(OrdTerm(subterm), (index, count))| (index, subterm, count))
+            .collect::<Vec<_>>();
+        common.sort_by_key(|(index, _, _)| *index);
+        common.into_iter()
+            .map(|(_, subterm, count)| (subterm, count))
+            .collect()
+    }
+}
+
+impl<T: Eq + Hash> Term<T> {
+    /// Returns the identifiers of the [Term]'s free variables, i.e. the variables that are not bound by any enclosing abstraction.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::collections::HashSet;
+    /// 
+    /// use lamb::*;
+    /// 
+    /// assert_eq!(lambda!(λ x. x y).free_vars(), HashSet::from([&"y"]));
+    /// assert_eq!(lambda!((λ x. x) x).free_vars(), HashSet::from([&"x"]));
+    /// assert_eq!(lambda!(λ x. (λ x. x) x).free_vars(), HashSet::new());
+    /// assert_eq!(lambda!(f (λ f. f g)).free_vars(), HashSet::from([&"f", &"g"]));
+    /// ```
+    pub fn free_vars(&self) -> HashSet<&T> {
+        let mut free = HashSet::new();
+        self.visit_free_vars(&mut Vec::new(), &mut |var| {
+            free.insert(var);
+        });
+        free
+    }
+
+    /// Checks whether the [Term] is closed, i.e. whether it has no free variables (see [Term::free_vars]).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// assert!(lambda!(λ x y. x y).is_closed());
+    /// assert!(lambda!(λ x. (λ x. x) x).is_closed());
+    /// assert!(!lambda!(λ x. x y).is_closed());
+    /// assert!(!lambda!((λ x. x) x).is_closed());
+    /// ```
+    pub fn is_closed(&self) -> bool {
+        self.free_vars().is_empty()
+    }
+}
+
+impl<T: PartialEq> Term<T> {
+    // Returns the identifiers of the free variables in the order they first occur, for callers that need a deterministic order or cannot hash identifiers
+    pub(crate) fn free_vars_ordered(&self) -> Vec<&T> {
+        let mut free = Vec::new();
+        self.visit_free_vars(&mut Vec::new(), &mut |var| {
+            if !free.contains(&var) {
+                free.push(var);
+            }
+        });
+        free
+    }
+
+    // Every query about free variables is built on this walk, so that shadowing is handled in exactly one place
+    fn visit_free_vars<'t, F: FnMut(&'t T)>(&'t self, bound: &mut Vec<&'t T>, visit: &mut F) {
+        match self {
+            Self::Var(var) => {
+                if !bound.contains(&var) {
+                    visit(var);
+                }
+            },
+            Self::Abs(param, body) => {
+                bound.push(param);
+                body.visit_free_vars(bound, visit);
+                bound.pop();
+            },
+            Self::App(func, arg) => {
+                func.visit_free_vars(bound, visit);
+                arg.visit_free_vars(bound, visit);
+            },
+        }
+    }
+}
+
+impl<T: Eq, S> Term<(T, S)> {
+    /// Returns the annotation (such as a source span) of the first free occurrence of a variable in the [Term], if there is one.
+    /// 
+    /// Annotations on formal parameters are ignored when comparing variables, so shadowing is respected regardless of where the parameters occur.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = Term::app(
+    ///     Term::abs(("x", 0..2), Term::var(("x", 4..5))),
+    ///     Term::app(Term::var(("x", 7..8)), Term::var(("x", 9..10))),
+    /// );
+    /// assert_eq!(term.free_var_first_occurrence_span(&"x"), Some(&(7..8)));
+    /// assert_eq!(term.free_var_first_occurrence_span(&"y"), None);
+    /// ```
+    pub fn free_var_first_occurrence_span(&self, var: &T) -> Option<&S> {
+        match self {
+            Self::Var((other, span)) => (other == var).then_some(span),
+            Self::Abs((param, _), _) if param == var => None,
+            Self::Abs(_, body) => body.free_var_first_occurrence_span(var),
+            Self::App(func, arg) => func.free_var_first_occurrence_span(var)
+                .or_else(|| arg.free_var_first_occurrence_span(var)),
+        }
+    }
+}
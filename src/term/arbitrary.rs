@@ -0,0 +1,71 @@
+//! Generating arbitrary [Term]s for property testing and fuzzing.
+
+use arbitrary::Arbitrary;
+use arbitrary::Result;
+use arbitrary::Unstructured;
+
+use crate::Term;
+
+// Kept small so that generated terms often shadow binders and reuse free variables
+const BINDERS: [&str; 4] = ["x", "y", "z", "w"];
+const FREE_VARS: [&str; 3] = ["a", "b", "c"];
+const MAX_SIZE: usize = 64;
+
+/// Generates [Term]s of up to 64 nodes.
+/// 
+/// Variables are either bound by an enclosing abstraction or free, so generated [Term]s may be closed or open.
+/// Binder names are drawn from a small set, so shadowing is common.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use arbitrary::Arbitrary;
+/// use arbitrary::Unstructured;
+/// 
+/// use lamb::*;
+/// 
+/// for seed in 0..64u8 {
+///     let data = (0..255u8).map(|byte| byte.wrapping_mul(seed).wrapping_add(seed)).collect::<Vec<_>>();
+///     let term = Term::<String>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+///     let local_nameless = LocalNamelessTerm::from(&term);
+///     let classic = Term::try_from(&local_nameless).unwrap();
+///     assert_eq!(LocalNamelessTerm::from(&classic), local_nameless);
+/// }
+/// ```
+impl<'a> Arbitrary<'a> for Term<String> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let size = u.int_in_range(1..=MAX_SIZE)?;
+        arbitrary_term(u, &mut Vec::new(), size)
+    }
+}
+
+fn arbitrary_term(u: &mut Unstructured, scope: &mut Vec<&'static str>, size: usize) -> Result<Term<String>> {
+    let choice = match size {
+        1 => 0,
+        2 => u.int_in_range(0..=1)?,
+        _ => u.int_in_range(0..=2)?,
+    };
+    match choice {
+        0 => {
+            let name = if !scope.is_empty() && u.arbitrary()? {
+                *u.choose(scope)?
+            } else {
+                *u.choose(&FREE_VARS)?
+            };
+            Ok(Term::var(name.to_string()))
+        },
+        1 => {
+            let param = *u.choose(&BINDERS)?;
+            scope.push(param);
+            let body = arbitrary_term(u, scope, size - 1);
+            scope.pop();
+            Ok(Term::abs(param.to_string(), body?))
+        },
+        _ => {
+            let func_size = u.int_in_range(1..=size - 2)?;
+            let func = arbitrary_term(u, scope, func_size)?;
+            let arg = arbitrary_term(u, scope, size - 1 - func_size)?;
+            Ok(Term::app(func, arg))
+        },
+    }
+}
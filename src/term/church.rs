@@ -0,0 +1,260 @@
+//! Constructing Church-encoded [Term]s and recognising them by their shape.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+use crate::BetaReduce;
+use crate::Term;
+use crate::Var;
+
+/// The value of a β-reduced [Term], classified by the Church encoding its shape matches (see [Term::reduce_to_value]).
+/// 
+/// Some [Term]s have the shape of more than one encoding - notably, `λa b. b` is the number zero, the boolean `false`, and the empty list at the same time.
+/// Such [Term]s are always classified as numbers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReducedValue<T> {
+    /// A Church-encoded natural number of the form `λf x. f (f (... (f x)))`.
+    Numeral(usize),
+    /// A Church-encoded boolean of the form `λt f. t` or `λt f. f`.
+    Boolean(bool),
+    /// A Church-encoded list of the form `λc n. c a (c b (... n))`, with each of its elements classified in turn.
+    List(Vec<ReducedValue<T>>),
+    /// A [Term] that matches no known encoding.
+    Term(Term<T>),
+}
+
+/// Displays numbers and booleans as their values, lists as their comma-separated elements in square brackets, and other [Term]s as themselves.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// 
+/// let list = ReducedValue::List(vec![ReducedValue::Numeral(1), ReducedValue::Boolean(false), ReducedValue::Term(lambda!(λ x. x))]);
+/// assert_eq!(list.to_string(), "[1, false, λx. x]");
+/// ```
+impl<T: Display> Display for ReducedValue<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Numeral(number) => write!(formatter, "{}", number),
+            Self::Boolean(boolean) => write!(formatter, "{}", boolean),
+            Self::List(elements) => {
+                write!(formatter, "[")?;
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        write!(formatter, ", ")?;
+                    }
+                    write!(formatter, "{}", element)?;
+                }
+                write!(formatter, "]")
+            },
+            Self::Term(term) => write!(formatter, "{}", term),
+        }
+    }
+}
+
+impl<T: PartialEq> Term<T> {
+    /// Checks whether the [Term] has the shape of a Church-encoded natural number, up to α-equivalence.
+    /// 
+    /// This is the case for [Term]s of the form `λf x. f (f (... (f x)))`, with any number of applications of `f`.
+    /// The value of the number is not computed.
+    /// Note that the Church-encoded number zero has the same shape as the Church-encoded boolean `false`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// assert!(lambda!(λ f x. f (f (f x))).is_church_numeral());
+    /// assert!(lambda!(λ f x. x).is_church_numeral());
+    /// assert!(!lambda!(λ t f. t).is_church_numeral());
+    /// assert!(!lambda!(λ f x. x f).is_church_numeral());
+    /// ```
+    pub fn is_church_numeral(&self) -> bool {
+        self.church_numeral().is_some()
+    }
+
+    /// Checks whether the [Term] has the shape of a Church-encoded boolean, up to α-equivalence.
+    /// 
+    /// This is the case for [Term]s of the form `λt f. t` or `λt f. f`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// assert!(lambda!(λ t f. t).is_church_boolean());
+    /// assert!(lambda!(λ t f. f).is_church_boolean());
+    /// assert!(!lambda!(λ x x. y).is_church_boolean());
+    /// assert!(!lambda!(λ f x. f (f x)).is_church_boolean());
+    /// ```
+    pub fn is_church_boolean(&self) -> bool {
+        self.church_boolean().is_some()
+    }
+
+    fn church_numeral(&self) -> Option<usize> {
+        let (func, arg, mut body) = self.binary_abs()?;
+        let mut number = 0;
+        loop {
+            match body {
+                Self::Var(var) if var == arg => return Some(number),
+                Self::App(lhs, rhs) if func != arg && matches!(lhs.as_ref(), Self::Var(var) if var == func) => body = rhs,
+                _ => return None,
+            }
+            number += 1;
+        }
+    }
+
+    fn church_boolean(&self) -> Option<bool> {
+        match self.binary_abs()? {
+            // The second parameter shadows the first if they are the same
+            (_, f, Self::Var(var)) if var == f => Some(false),
+            (t, _, Self::Var(var)) if var == t => Some(true),
+            _ => None,
+        }
+    }
+
+    fn church_list(&self) -> Option<Vec<&Self>> {
+        let (cons, nil, mut body) = self.binary_abs()?;
+        let mut elements = Vec::new();
+        loop {
+            match body {
+                Self::Var(var) if var == nil => return Some(elements),
+                Self::App(lhs, tail) if cons != nil => match lhs.as_ref() {
+                    Self::App(func, head) if matches!(func.as_ref(), Self::Var(var) if var == cons) && !head.free_vars_ordered().iter().any(|var| *var == cons || *var == nil) => {
+                        elements.push(head.as_ref());
+                        body = tail;
+                    },
+                    _ => return None,
+                },
+                _ => return None,
+            }
+        }
+    }
+
+    fn binary_abs(&self) -> Option<(&T, &T, &Self)> {
+        match self {
+            Self::Abs(first, body) => match body.as_ref() {
+                Self::Abs(second, body) => Some((first, second, body)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl<T: Clone> Term<T> {
+    /// Returns the Church-encoded natural number `n`, of the form `λf x. f (f (... (f x)))`, using the specified identifiers for its formal parameters.
+    /// 
+    /// The [Term] is built iteratively, so it can be constructed for arbitrarily large numbers without overflowing the stack.
+    /// However, its height grows linearly with `n`, and most operations on [Term]s (including dropping them) are recursive.
+    /// Large numbers should therefore be disposed of using [Term::dispose], or constructed using [Term::height_balanced_numeral] instead.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// assert_eq!(Term::numeral(3, "f", "x"), lambda!(λ f x. f (f (f x))));
+    /// 
+    /// let large = Term::numeral(200_000, "f", "x");
+    /// assert!(large.is_church_numeral());
+    /// large.dispose();
+    /// ```
+    pub fn numeral(n: usize, func: T, arg: T) -> Self {
+        let mut body = Self::var(arg.clone());
+        for _ in 0..n {
+            body = Self::app(Self::var(func.clone()), body);
+        }
+        Self::abs(func, Self::abs(arg, body))
+    }
+
+    /// Returns a [Term] that β-reduces to the Church-encoded natural number `n` (see [Term::numeral]), but whose height and size only grow logarithmically with `n`.
+    /// 
+    /// The [Term] composes `f` with itself by repeated doubling, following the binary representation of `n`.
+    /// This makes it safe to construct, drop, and pass around for large numbers, as long as it is not fully β-reduced.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let thirteen = Term::height_balanced_numeral(13, "f", "x");
+    /// assert_eq!(thirteen.beta_reduced(&Normal::default()).into_term(), Term::numeral(13, "f", "x"));
+    /// 
+    /// let large = Term::height_balanced_numeral(1_000_000, "f", "x");
+    /// assert!(large.count_applications() < 200);
+    /// ```
+    pub fn height_balanced_numeral(n: usize, func: T, arg: T) -> Self {
+        Self::abs(func.clone(), Self::power(n, &func, &arg))
+    }
+
+    // Returns a term equivalent to `λx. f (f (... (f x)))` with `n` applications of `f`, where `f` is free
+    fn power(n: usize, func: &T, arg: &T) -> Self {
+        if n == 0 {
+            return Self::abs(arg.clone(), Self::var(arg.clone()));
+        }
+        // The doubling combinator `λf x. f (f x)` is closed, so reusing the identifiers cannot cause capture
+        let double = Self::abs(func.clone(), Self::abs(arg.clone(), Self::app(
+            Self::var(func.clone()),
+            Self::app(Self::var(func.clone()), Self::var(arg.clone())),
+        )));
+        let doubled = Self::app(double, Self::power(n / 2, func, arg));
+        if n % 2 == 1 {
+            Self::abs(arg.clone(), Self::app(
+                Self::var(func.clone()),
+                Self::app(doubled, Self::var(arg.clone())),
+            ))
+        } else {
+            doubled
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> Term<T> {
+    /// Classifies the [Term] by the Church encoding its shape matches, without β-reducing it.
+    pub(crate) fn to_value(&self) -> ReducedValue<T> {
+        if let Some(number) = self.church_numeral() {
+            ReducedValue::Numeral(number)
+        } else if let Some(boolean) = self.church_boolean() {
+            ReducedValue::Boolean(boolean)
+        } else if let Some(elements) = self.church_list() {
+            ReducedValue::List(elements.into_iter()
+                .map(Self::to_value)
+                .collect())
+        } else {
+            ReducedValue::Term(self.clone())
+        }
+    }
+}
+
+impl<T: Clone + Eq> Term<T> {
+    /// β-reduces the [Term] up to a certain limit using the specified [BetaReduce] `impl`ementation, and classifies the result as a [ReducedValue].
+    /// 
+    /// Elements of Church-encoded lists are classified in turn, so lists of numbers or booleans are recognised as such.
+    /// [Term]s that match no known encoding (including those that did not reach β-normal form within the limit) are returned as they are.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let two = lambda!(λ f x. f (f x));
+    /// let succ = lambda!(λ n f x. f (n f x));
+    /// assert_eq!(app!(succ, two).reduce_to_value(100, &Normal::default()), ReducedValue::Numeral(3));
+    /// 
+    /// let not = lambda!(λ b t f. b f t);
+    /// let fls = lambda!(λ t f. f);
+    /// assert_eq!(app!(not, fls).reduce_to_value(100, &Normal::default()), ReducedValue::Boolean(true));
+    /// 
+    /// let list = lambda!(λ c n. c (λ t f. t) (c (λ f x. f x) n));
+    /// assert_eq!(list.reduce_to_value(100, &Normal::default()), ReducedValue::List(vec![ReducedValue::Boolean(true), ReducedValue::Numeral(1)]));
+    /// 
+    /// assert_eq!(lambda!((λ x. x) y).reduce_to_value(100, &Normal::default()), ReducedValue::Term(lambda!(y)));
+    /// ```
+    pub fn reduce_to_value<B: BetaReduce<Var<T>>>(&self, limit: usize, reducer: &B) -> ReducedValue<T> {
+        self.beta_reduced_limit(limit, reducer)
+            .to_value()
+    }
+}
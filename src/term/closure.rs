@@ -0,0 +1,80 @@
+//! Closure conversion of [Term]s, making the environments of abstractions explicit.
+
+use crate::Term;
+
+/// A lambda calculus term in which every abstraction is a closure that explicitly captures the variables it uses from its enclosing scope.
+/// 
+/// [ClosureTerm]s are produced by [Term::closure_convert].
+/// Inside the body of a closure, only the closure's own formal parameter and variables that are free in the entire [Term] are referred to by name.
+/// Variables bound by an enclosing abstraction are instead read from the closure's environment by index.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClosureTerm<T> {
+    /// A variable, which is either the formal parameter of the innermost closure or free in the entire [Term].
+    Var(T),
+    /// A variable captured by the innermost closure, referred to by its index in the closure's environment.
+    Env(usize),
+    /// The creation of a closure, binding a formal parameter inside its body.
+    MkClosure {
+        /// The formal parameter of the closure.
+        param: T,
+        /// The captured variables, each referred to as it appears in the scope the closure is created in.
+        env: Vec<ClosureTerm<T>>,
+        /// The body of the closure.
+        body: Box<ClosureTerm<T>>,
+    },
+    /// An application of one [ClosureTerm] to another.
+    Apply(Box<ClosureTerm<T>>, Box<ClosureTerm<T>>),
+}
+
+impl<T: Clone + PartialEq> Term<T> {
+    /// Converts the [Term] to a [ClosureTerm], turning every abstraction into a closure that captures the variables it uses from enclosing abstractions.
+    /// 
+    /// Captured variables are stored in the order of their first occurrence in the abstraction's body.
+    /// Variables that are free in the entire [Term] are treated as globals and are never captured.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!(λ x. λ y. x);
+    /// assert_eq!(term.closure_convert(), ClosureTerm::MkClosure {
+    ///     param: "x",
+    ///     env: vec![],
+    ///     body: Box::new(ClosureTerm::MkClosure {
+    ///         param: "y",
+    ///         env: vec![ClosureTerm::Var("x")],
+    ///         body: Box::new(ClosureTerm::Env(0)),
+    ///     }),
+    /// });
+    /// ```
+    pub fn closure_convert(&self) -> ClosureTerm<T> {
+        self.closure_convert_in(None, &[])
+    }
+
+    fn closure_convert_in(&self, param: Option<&T>, captured: &[&T]) -> ClosureTerm<T> {
+        match self {
+            Self::Var(var) if param == Some(var) => ClosureTerm::Var(var.clone()),
+            Self::Var(var) => match captured.iter().position(|captured| *captured == var) {
+                Some(index) => ClosureTerm::Env(index),
+                None => ClosureTerm::Var(var.clone()),
+            },
+            Self::Abs(inner_param, body) => {
+                // Only variables bound by an enclosing abstraction are captured, and those are always either the current parameter or already captured
+                let mut free = body.free_vars_ordered();
+                free.retain(|var| *var != inner_param && (param == Some(*var) || captured.contains(var)));
+                ClosureTerm::MkClosure {
+                    param: inner_param.clone(),
+                    env: free.iter()
+                        .map(|var| Self::Var((*var).clone()).closure_convert_in(param, captured))
+                        .collect(),
+                    body: Box::new(body.closure_convert_in(Some(inner_param), &free)),
+                }
+            },
+            Self::App(func, arg) => ClosureTerm::Apply(
+                Box::new(func.closure_convert_in(param, captured)),
+                Box::new(arg.closure_convert_in(param, captured)),
+            ),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Conversion of [Term]s to and from combinatory logic notation.
+
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use crate::Term;
+
+/// Represents possible errors that can occur when parsing a [Term] from combinatory logic notation using [Term::from_combinators].
+#[derive(Debug, Eq, PartialEq)]
+pub enum CombinatorParseError {
+    /// A character that is neither a letter, a parenthesis, nor whitespace was encountered, or a parenthesis was encountered where it is not allowed.
+    /// 
+    /// This contains the byte offset in the input at which the character was encountered.
+    Unexpected(usize),
+    /// The input ended while a combinator or a closing parenthesis was still expected.
+    UnexpectedEnd,
+}
+
+impl Term<String> {
+    /// Parses a [Term] from combinatory logic notation, such as `S(KI)I`.
+    /// 
+    /// Every letter is a separate combinator, even when letters are not separated by whitespace (unlike in the REPL's syntax, where `SK` is a single identifier).
+    /// Each combinator becomes a free variable named after its letter, and sequences of combinators are left-associative applications.
+    /// Parentheses group combinators, and whitespace is ignored.
+    /// 
+    /// See [Term::from_combinators_expanded] for replacing the letters with the combinators they denote.
+    /// 
+    /// # Errors
+    /// 
+    /// This function returns a [CombinatorParseError] if the input contains characters other than letters, parentheses, and whitespace, if its parentheses are unbalanced, or if it (or a pair of parentheses) contains no combinators.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let var = |name: &str| Term::var(name.to_string());
+    /// 
+    /// assert_eq!(Term::from_combinators("SKK"), Ok(Term::app(Term::app(var("S"), var("K")), var("K"))));
+    /// assert_eq!(Term::from_combinators("S(KI) I"), Ok(Term::app(Term::app(var("S"), Term::app(var("K"), var("I"))), var("I"))));
+    /// 
+    /// assert_eq!(Term::from_combinators("S(K"), Err(CombinatorParseError::UnexpectedEnd));
+    /// assert_eq!(Term::from_combinators("S()"), Err(CombinatorParseError::Unexpected(2)));
+    /// assert_eq!(Term::from_combinators("S.K"), Err(CombinatorParseError::Unexpected(1)));
+    /// ```
+    pub fn from_combinators(source: &str) -> Result<Self, CombinatorParseError> {
+        CombinatorParser::parse(source, |symbol| Term::var(symbol.to_string()))
+    }
+
+    /// Parses a [Term] from combinatory logic notation like [Term::from_combinators], but replaces every letter that denotes a combinator in the [prelude](crate::prelude) with that combinator.
+    /// 
+    /// The letters are looked up using [by_symbol](crate::prelude::combinators::by_symbol), and letters that do not denote any combinator are left as free variables.
+    /// 
+    /// # Errors
+    /// 
+    /// This function returns a [CombinatorParseError] under the same conditions as [Term::from_combinators].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = Term::from_combinators_expanded("SKKx").unwrap();
+    /// assert_eq!(term.beta_reduced(&Normal::default()).term, Term::var("x".to_string()));
+    /// ```
+    #[cfg(feature = "prelude")]
+    pub fn from_combinators_expanded(source: &str) -> Result<Self, CombinatorParseError> {
+        use crate::prelude::combinators::by_symbol;
+
+        CombinatorParser::parse(source, |symbol| match by_symbol(symbol) {
+            Some(combinator) => to_owned_names(&combinator),
+            None => Term::var(symbol.to_string()),
+        })
+    }
+}
+
+impl<T: Clone + Eq + From<&'static str>> Term<T> {
+    /// Eliminates a variable from the [Term] using bracket abstraction, returning a [Term] that behaves like an abstraction over that variable but contains no abstractions over it.
+    /// 
+    /// The result uses the combinators `S`, `K`, and `I`, represented as free variables with those identifiers (like those produced by [Term::from_combinators]).
+    /// Applying the result to any argument β-reduces to the same [Term] as substituting the argument for the variable, once the combinators are replaced with their definitions.
+    /// 
+    /// The result is built using the following rules:
+    /// - Subterms in which the variable does not occur free become `K M`.
+    /// - The variable itself becomes `I`.
+    /// - Applications `M N` become `S [M] [N]`, where `[M]` and `[N]` are `M` and `N` with the variable eliminated.
+    /// - Abstractions `λy. M` (in which the variable occurs free) first have `y` eliminated from `M`, and then the variable eliminated from the result.
+    /// 
+    /// Note that the result may contain other free variables named `S`, `K`, or `I` if the [Term] already did, which are indistinguishable from the combinators.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// assert_eq!(lambda!(x).abstract_out(&"x"), lambda!(I));
+    /// assert_eq!(lambda!(y).abstract_out(&"x"), lambda!(K y));
+    /// assert_eq!(lambda!(f x).abstract_out(&"x"), lambda!(S (K f) I));
+    /// 
+    /// // The inner abstraction becomes S I (K x) first
+    /// assert_eq!(lambda!(λ y. y x).abstract_out(&"x"), lambda!(S (K (S I)) (S (K K) I)));
+    /// ```
+    pub fn abstract_out(&self, var: &T) -> Self {
+        match self {
+            _ if self.occurrences(var) == 0 => Self::app(Self::var(T::from("K")), self.clone()),
+            // The variable occurs free, so this must be the variable itself
+            Self::Var(_) => Self::var(T::from("I")),
+            Self::Abs(param, body) => body.abstract_out(param).abstract_out(var),
+            Self::App(func, arg) => Self::app(Self::app(Self::var(T::from("S")), func.abstract_out(var)), arg.abstract_out(var)),
+        }
+    }
+}
+
+#[cfg(feature = "prelude")]
+fn to_owned_names(term: &Term<&str>) -> Term<String> {
+    match term {
+        Term::Var(var) => Term::var(var.to_string()),
+        Term::Abs(param, body) => Term::abs(param.to_string(), to_owned_names(body)),
+        Term::App(func, arg) => Term::app(to_owned_names(func), to_owned_names(arg)),
+    }
+}
+
+struct CombinatorParser<'s, F> {
+    chars: Peekable<CharIndices<'s>>,
+    combinator: F,
+}
+
+impl<'s, F: Fn(char) -> Term<String>> CombinatorParser<'s, F> {
+    fn parse(source: &'s str, combinator: F) -> Result<Term<String>, CombinatorParseError> {
+        let mut parser = Self {
+            chars: source.char_indices().peekable(),
+            combinator,
+        };
+        let term = parser.sequence()?;
+        // Only a closing parenthesis without a matching opening one can end a sequence early
+        match parser.chars.next() {
+            Some((index, _)) => Err(CombinatorParseError::Unexpected(index)),
+            None => Ok(term),
+        }
+    }
+
+    fn sequence(&mut self) -> Result<Term<String>, CombinatorParseError> {
+        let mut term = self.atom()?;
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                None | Some((_, ')')) => break Ok(term),
+                Some(_) => term = Term::app(term, self.atom()?),
+            }
+        }
+    }
+
+    fn atom(&mut self) -> Result<Term<String>, CombinatorParseError> {
+        self.skip_whitespace();
+        match self.chars.next() {
+            Some((_, '(')) => {
+                let term = self.sequence()?;
+                match self.chars.next() {
+                    Some((_, ')')) => Ok(term),
+                    Some((index, _)) => Err(CombinatorParseError::Unexpected(index)),
+                    None => Err(CombinatorParseError::UnexpectedEnd),
+                }
+            },
+            Some((_, symbol)) if symbol.is_alphabetic() => Ok((self.combinator)(symbol)),
+            Some((index, _)) => Err(CombinatorParseError::Unexpected(index)),
+            None => Err(CombinatorParseError::UnexpectedEnd),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, char)| char.is_whitespace()).is_some() {}
+    }
+}
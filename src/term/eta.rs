@@ -0,0 +1,80 @@
+//! η-expansion of [Term]s.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::BetaReduce;
+use crate::Fresh;
+use crate::Term;
+use crate::Var;
+
+impl<T: Clone + Eq + Hash + Fresh> Term<T> {
+    /// Returns the β-normal η-long form of the [Term], β-reducing it using the specified [BetaReduce] `impl`ementation and then η-expanding it.
+    /// 
+    /// Since untyped [Term]s carry no information about how many arguments a function expects, the arities of free variables must be supplied.
+    /// Every application whose head is a free variable with a known arity is then η-expanded until the variable is applied to exactly that many arguments, by abstracting over fresh formal parameters (see [Fresh]).
+    /// Free variables without a known arity and bound variables are assumed to take no arguments, and are therefore never η-expanded.
+    /// Applications with more arguments than their head's arity are left as they are.
+    /// 
+    /// Like [Term::beta_reduced], this does not terminate if the [Term] has no β-normal form.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::collections::HashMap;
+    /// 
+    /// use lamb::*;
+    /// 
+    /// let arities = HashMap::from([("f".to_string(), 2)]);
+    /// 
+    /// let term = lambda_str!(f);
+    /// assert_eq!(term.eta_long_nf(&arities, &Normal::default()), lambda_str!(λ x x1. f x x1));
+    /// 
+    /// let term = lambda_str!((λ g. g x) f);
+    /// assert_eq!(term.eta_long_nf(&arities, &Normal::default()), lambda_str!(λ x1. f x x1));
+    /// ```
+    pub fn eta_long_nf<B: BetaReduce<Var<T>>>(&self, arities: &HashMap<T, usize>, reducer: &B) -> Term<T> {
+        let normal = self.beta_reduced(reducer).into_term();
+        // Fresh formal parameters must not clash with any existing identifier, nor with a variable that has an arity
+        let mut taken = normal.names()
+            .into_iter()
+            .chain(arities.keys())
+            .cloned()
+            .collect();
+        normal.eta_expanded(arities, &mut Vec::new(), &mut taken)
+    }
+
+    fn eta_expanded<'t>(&'t self, arities: &HashMap<T, usize>, bound: &mut Vec<&'t T>, taken: &mut HashSet<T>) -> Term<T> {
+        if let Self::Abs(param, body) = self {
+            bound.push(param);
+            let body = body.eta_expanded(arities, bound, taken);
+            bound.pop();
+            return Term::abs(param.clone(), body);
+        }
+
+        // The head of a spine is never an application, and only abstractions have anything to η-expand inside them
+        let (head, args) = self.spine();
+        let (head, arity) = match head {
+            Self::Var(var) if !bound.contains(&var) => (head.clone(), arities.get(var).copied().unwrap_or(0)),
+            Self::Var(_) => (head.clone(), 0),
+            _ => (head.eta_expanded(arities, bound, taken), 0),
+        };
+        let args = args.iter()
+            .map(|arg| arg.eta_expanded(arities, bound, taken))
+            .collect::<Vec<_>>();
+
+        let params = (args.len()..arity)
+            .map(|_| {
+                let param = T::fresh(|name| taken.contains(name));
+                taken.insert(param.clone());
+                param
+            })
+            .collect::<Vec<_>>();
+        let term = head.apply_all(args)
+            .apply_all(params.iter().cloned().map(Term::var));
+        params.into_iter()
+            .rev()
+            .fold(term, |body, param| Term::abs(param, body))
+    }
+}
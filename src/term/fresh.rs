@@ -0,0 +1,98 @@
+//! Generating fresh identifiers that do not clash with existing ones.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::Term;
+use crate::TraversalOrder;
+
+/// Represents identifiers that new, unused identifiers can be generated from.
+/// 
+/// This is needed by operations that introduce new formal parameters or free variables into [Term]s, since those must not clash with the identifiers already in use.
+pub trait Fresh: Sized {
+    /// Returns an identifier for which the predicate returns `false`, without any particular identifier to start from.
+    fn fresh<P: FnMut(&Self) -> bool>(is_taken: P) -> Self;
+
+    /// Returns the identifier itself if the predicate returns `false` for it, or otherwise another identifier derived from it for which the predicate returns `false`.
+    fn freshen<P: FnMut(&Self) -> bool>(&self, is_taken: P) -> Self;
+}
+
+/// Generates identifiers by appending the smallest possible positive number, starting from `x` if there is no identifier to start from.
+/// 
+/// An underscore (`_`) is freshened as if there were no identifier to start from, since the underscore is only valid on its own and appending a number to it would not produce a valid identifier.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// 
+/// let taken = ["x".to_string(), "x1".to_string(), "y".to_string()];
+/// assert_eq!(String::fresh(|name| taken.contains(name)), "x2");
+/// assert_eq!("y".to_string().freshen(|name| taken.contains(name)), "y1");
+/// assert_eq!("z".to_string().freshen(|name| taken.contains(name)), "z");
+/// assert_eq!("_".to_string().freshen(|name| name == "_"), "x");
+/// ```
+impl Fresh for String {
+    fn fresh<P: FnMut(&Self) -> bool>(is_taken: P) -> Self {
+        "x".to_string().freshen(is_taken)
+    }
+
+    fn freshen<P: FnMut(&Self) -> bool>(&self, mut is_taken: P) -> Self {
+        if !is_taken(self) {
+            return self.clone();
+        }
+        if self == "_" {
+            return Self::fresh(is_taken);
+        }
+        (1..)
+            .map(|suffix| format!("{}{}", self, suffix))
+            .find(|name| !is_taken(name))
+            .unwrap()
+    }
+}
+
+/// Generates identifiers by counting upwards, starting from 0 if there is no identifier to start from.
+impl Fresh for usize {
+    fn fresh<P: FnMut(&Self) -> bool>(is_taken: P) -> Self {
+        0.freshen(is_taken)
+    }
+
+    fn freshen<P: FnMut(&Self) -> bool>(&self, mut is_taken: P) -> Self {
+        (*self..)
+            .find(|id| !is_taken(id))
+            .unwrap()
+    }
+}
+
+impl<T: Eq + Hash> Term<T> {
+    /// Returns every identifier used in the [Term], whether as a free variable, a bound variable, or a formal parameter.
+    pub(crate) fn names(&self) -> HashSet<&T> {
+        self.traverse(TraversalOrder::PreOrder)
+            .filter_map(|term| match term {
+                Self::Var(name) | Self::Abs(name, _) => Some(name),
+                Self::App(_, _) => None,
+            })
+            .collect()
+    }
+}
+
+impl<T: Eq + Hash + Fresh> Term<T> {
+    /// Returns an identifier that is not used anywhere in the [Term] (see [Fresh::fresh]), so it can be introduced as a new free variable without clashing with any existing one.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = Term::abs("x".to_string(), Term::app(Term::var("x".to_string()), Term::var("x1".to_string())));
+    /// let fresh = term.fresh_free();
+    /// assert_eq!(fresh, "x2");
+    /// 
+    /// let open = Term::app(term, Term::var(fresh));
+    /// assert_eq!(open.to_string(), "(λx. x x1) x2");
+    /// ```
+    pub fn fresh_free(&self) -> T {
+        let names = self.names();
+        T::fresh(|name| names.contains(name))
+    }
+}
@@ -0,0 +1,254 @@
+//! Conversion of [Term]s to and from a stable JSON representation of their abstract syntax tree.
+//! 
+//! Every node of a [Term] is represented as a JSON object with a `"kind"` field, along with fields that depend on the kind:
+//! - Variables are represented as `{"kind":"var","name":...}`, where `name` is the variable's identifier as a string.
+//! - Abstractions are represented as `{"kind":"abs","param":...,"body":...}`, where `param` is the formal parameter's identifier as a string and `body` is a node.
+//! - Applications are represented as `{"kind":"app","func":...,"arg":...}`, where `func` and `arg` are nodes.
+//! 
+//! This schema is an interoperability contract for consumers not written in Rust, and will not change between versions.
+
+use std::fmt::Display;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use crate::Term;
+
+/// Represents possible errors that can occur when parsing a [Term] from its JSON representation using [Term::from_json_ast].
+#[derive(Debug, Eq, PartialEq)]
+pub enum JsonAstError {
+    /// The input is not valid JSON, or uses JSON values that never appear in the schema (such as numbers or arrays).
+    /// 
+    /// This contains the byte offset in the input at which the error was encountered.
+    Syntax(usize),
+    /// A node's `"kind"` field is not one of `"var"`, `"abs"`, or `"app"`.
+    UnknownKind(String),
+    /// A node is missing a field required by its kind.
+    MissingField(&'static str),
+    /// A node has a field that is not part of its kind, or has the same field more than once.
+    UnexpectedField(String),
+}
+
+impl<T: Display> Term<T> {
+    /// Converts the [Term] to its JSON representation, as described in the [module documentation](self).
+    /// 
+    /// The output contains no whitespace, and the fields of every node appear in the order given in the schema.
+    /// Identifiers are converted to strings using their [Display] `impl`ementation.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!(λ x. f x);
+    /// assert_eq!(
+    ///     term.to_json_ast(),
+    ///     r#"{"kind":"abs","param":"x","body":{"kind":"app","func":{"kind":"var","name":"f"},"arg":{"kind":"var","name":"x"}}}"#,
+    /// );
+    /// ```
+    pub fn to_json_ast(&self) -> String {
+        let mut json = String::new();
+        self.write_json_ast(&mut json);
+        json
+    }
+
+    fn write_json_ast(&self, json: &mut String) {
+        match self {
+            Self::Var(var) => {
+                json.push_str(r#"{"kind":"var","name":"#);
+                write_json_string(&var.to_string(), json);
+                json.push('}');
+            },
+            Self::Abs(param, body) => {
+                json.push_str(r#"{"kind":"abs","param":"#);
+                write_json_string(&param.to_string(), json);
+                json.push_str(r#","body":"#);
+                body.write_json_ast(json);
+                json.push('}');
+            },
+            Self::App(func, arg) => {
+                json.push_str(r#"{"kind":"app","func":"#);
+                func.write_json_ast(json);
+                json.push_str(r#","arg":"#);
+                arg.write_json_ast(json);
+                json.push('}');
+            },
+        }
+    }
+}
+
+impl Term<String> {
+    /// Parses a [Term] from its JSON representation, as described in the [module documentation](self).
+    /// 
+    /// Whitespace is allowed between JSON tokens, and the fields of a node may appear in any order.
+    /// 
+    /// # Errors
+    /// 
+    /// This function returns a [JsonAstError] if the input is not valid JSON or does not follow the schema.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = Term::abs("x".to_string(), Term::app(Term::var("f".to_string()), Term::var("x".to_string())));
+    /// assert_eq!(Term::from_json_ast(&term.to_json_ast()), Ok(term));
+    /// 
+    /// let json = r#"{ "body": { "name": "x", "kind": "var" }, "kind": "abs", "param": "x" }"#;
+    /// assert_eq!(Term::from_json_ast(json), Ok(Term::abs("x".to_string(), Term::var("x".to_string()))));
+    /// 
+    /// assert_eq!(Term::from_json_ast(r#"{"kind":"var"}"#), Err(JsonAstError::MissingField("name")));
+    /// assert_eq!(Term::from_json_ast(r#"{"kind":"let"}"#), Err(JsonAstError::UnknownKind("let".to_string())));
+    /// ```
+    pub fn from_json_ast(json: &str) -> Result<Self, JsonAstError> {
+        let mut parser = JsonAstParser {
+            chars: json.char_indices().peekable(),
+            len: json.len(),
+        };
+        let term = parser.node()?;
+        parser.skip_whitespace();
+        match parser.chars.next() {
+            Some((index, _)) => Err(JsonAstError::Syntax(index)),
+            None => Ok(term),
+        }
+    }
+}
+
+fn write_json_string(string: &str, json: &mut String) {
+    json.push('"');
+    for char in string.chars() {
+        match char {
+            '"' => json.push_str(r#"\""#),
+            '\\' => json.push_str(r"\\"),
+            '\n' => json.push_str(r"\n"),
+            '\r' => json.push_str(r"\r"),
+            '\t' => json.push_str(r"\t"),
+            char if char.is_control() => json.push_str(&format!(r"\u{:04x}", char as u32)),
+            char => json.push(char),
+        }
+    }
+    json.push('"');
+}
+
+struct JsonAstParser<'s> {
+    chars: Peekable<CharIndices<'s>>,
+    len: usize,
+}
+
+impl JsonAstParser<'_> {
+    fn node(&mut self) -> Result<Term<String>, JsonAstError> {
+        let (mut kind, mut name, mut param) = (None, None, None);
+        let (mut body, mut func, mut arg) = (None, None, None);
+        self.expect('{')?;
+        self.skip_whitespace();
+        if self.chars.peek().map(|(_, char)| *char) == Some('}') {
+            self.chars.next();
+        } else {
+            loop {
+                self.skip_whitespace();
+                let field = self.string()?;
+                self.skip_whitespace();
+                self.expect(':')?;
+                self.skip_whitespace();
+                let duplicate = match field.as_str() {
+                    "kind" => kind.replace(self.string()?).is_some(),
+                    "name" => name.replace(self.string()?).is_some(),
+                    "param" => param.replace(self.string()?).is_some(),
+                    "body" => body.replace(self.node()?).is_some(),
+                    "func" => func.replace(self.node()?).is_some(),
+                    "arg" => arg.replace(self.node()?).is_some(),
+                    _ => true,
+                };
+                if duplicate {
+                    return Err(JsonAstError::UnexpectedField(field));
+                }
+                self.skip_whitespace();
+                match self.chars.next() {
+                    Some((_, ',')) => (),
+                    Some((_, '}')) => break,
+                    Some((index, _)) => return Err(JsonAstError::Syntax(index)),
+                    None => return Err(JsonAstError::Syntax(self.len)),
+                }
+            }
+        }
+        let kind = kind.ok_or(JsonAstError::MissingField("kind"))?;
+        let term = match kind.as_str() {
+            "var" => Term::var(name.take().ok_or(JsonAstError::MissingField("name"))?),
+            "abs" => Term::abs(
+                param.take().ok_or(JsonAstError::MissingField("param"))?,
+                body.take().ok_or(JsonAstError::MissingField("body"))?,
+            ),
+            "app" => Term::app(
+                func.take().ok_or(JsonAstError::MissingField("func"))?,
+                arg.take().ok_or(JsonAstError::MissingField("arg"))?,
+            ),
+            _ => return Err(JsonAstError::UnknownKind(kind)),
+        };
+        // Any fields that were not taken above are not part of the node's kind
+        let leftover = [("name", name.is_some()), ("param", param.is_some()), ("body", body.is_some()), ("func", func.is_some()), ("arg", arg.is_some())];
+        match leftover.into_iter().find(|(_, present)| *present) {
+            Some((field, _)) => Err(JsonAstError::UnexpectedField(field.to_string())),
+            None => Ok(term),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, JsonAstError> {
+        self.expect('"')?;
+        let mut string = String::new();
+        loop {
+            match self.next()? {
+                (_, '"') => break Ok(string),
+                (_, '\\') => match self.next()? {
+                    (_, '"') => string.push('"'),
+                    (_, '\\') => string.push('\\'),
+                    (_, '/') => string.push('/'),
+                    (_, 'b') => string.push('\u{8}'),
+                    (_, 'f') => string.push('\u{c}'),
+                    (_, 'n') => string.push('\n'),
+                    (_, 'r') => string.push('\r'),
+                    (_, 't') => string.push('\t'),
+                    (index, 'u') => {
+                        let high = self.hex_escape()?;
+                        let code = if (0xD800..0xDC00).contains(&high) {
+                            self.expect('\\')?;
+                            self.expect('u')?;
+                            let low = self.hex_escape()?;
+                            if !(0xDC00..0xE000).contains(&low) {
+                                return Err(JsonAstError::Syntax(index));
+                            }
+                            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                        } else {
+                            high
+                        };
+                        string.push(char::from_u32(code).ok_or(JsonAstError::Syntax(index))?);
+                    },
+                    (index, _) => return Err(JsonAstError::Syntax(index)),
+                },
+                (index, char) if char.is_control() => return Err(JsonAstError::Syntax(index)),
+                (_, char) => string.push(char),
+            }
+        }
+    }
+
+    fn hex_escape(&mut self) -> Result<u32, JsonAstError> {
+        (0..4).try_fold(0, |code, _| {
+            let (index, char) = self.next()?;
+            let digit = char.to_digit(16).ok_or(JsonAstError::Syntax(index))?;
+            Ok(code * 16 + digit)
+        })
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), JsonAstError> {
+        match self.next()? {
+            (_, char) if char == expected => Ok(()),
+            (index, _) => Err(JsonAstError::Syntax(index)),
+        }
+    }
+
+    fn next(&mut self) -> Result<(usize, char), JsonAstError> {
+        self.chars.next().ok_or(JsonAstError::Syntax(self.len))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, char)| matches!(char, ' ' | '\t' | '\n' | '\r')).is_some() {}
+    }
+}
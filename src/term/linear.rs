@@ -0,0 +1,75 @@
+//! Checking [Term]s against substructural (linearity) policies.
+
+use crate::Term;
+
+/// A policy restricting how many times each formal parameter may be used in its abstraction's body.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LinearityPolicy {
+    /// Every formal parameter must be used exactly once.
+    Linear,
+    /// Every formal parameter must be used at most once.
+    Affine,
+    /// Every formal parameter must be used at least once.
+    Relevant,
+}
+
+/// A violation of a [LinearityPolicy], reported by [Term::satisfies_linearity].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LinearityViolation<T> {
+    /// The formal parameter is never used, which violates [LinearityPolicy::Linear] and [LinearityPolicy::Relevant].
+    Unused(T),
+    /// The formal parameter is used the specified number of times (more than once), which violates [LinearityPolicy::Linear] and [LinearityPolicy::Affine].
+    Duplicated(T, usize),
+}
+
+impl LinearityPolicy {
+    fn check<T: Clone>(self, param: &T, uses: usize) -> Result<(), LinearityViolation<T>> {
+        match (self, uses) {
+            (Self::Linear | Self::Relevant, 0) => Err(LinearityViolation::Unused(param.clone())),
+            (Self::Linear | Self::Affine, 2..) => Err(LinearityViolation::Duplicated(param.clone(), uses)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: Clone + Eq> Term<T> {
+    /// Checks whether every formal parameter in the [Term] is used in accordance with a [LinearityPolicy].
+    /// 
+    /// Abstractions are checked outermost and leftmost first, and the first violation found is returned.
+    /// Uses of a formal parameter that are shadowed by an inner formal parameter with the same identifier are not counted.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let id = lambda!(λ x. x);
+    /// let constant = lambda!(λ x y. x);
+    /// let dup = lambda!(λ f x. f x x);
+    /// 
+    /// assert_eq!(id.satisfies_linearity(LinearityPolicy::Linear), Ok(()));
+    /// assert_eq!(id.satisfies_linearity(LinearityPolicy::Affine), Ok(()));
+    /// assert_eq!(id.satisfies_linearity(LinearityPolicy::Relevant), Ok(()));
+    /// 
+    /// assert_eq!(constant.satisfies_linearity(LinearityPolicy::Linear), Err(LinearityViolation::Unused("y")));
+    /// assert_eq!(constant.satisfies_linearity(LinearityPolicy::Affine), Ok(()));
+    /// assert_eq!(constant.satisfies_linearity(LinearityPolicy::Relevant), Err(LinearityViolation::Unused("y")));
+    /// 
+    /// assert_eq!(dup.satisfies_linearity(LinearityPolicy::Linear), Err(LinearityViolation::Duplicated("x", 2)));
+    /// assert_eq!(dup.satisfies_linearity(LinearityPolicy::Affine), Err(LinearityViolation::Duplicated("x", 2)));
+    /// assert_eq!(dup.satisfies_linearity(LinearityPolicy::Relevant), Ok(()));
+    /// ```
+    pub fn satisfies_linearity(&self, policy: LinearityPolicy) -> Result<(), LinearityViolation<T>> {
+        match self {
+            Self::Var(_) => Ok(()),
+            Self::Abs(param, body) => {
+                policy.check(param, body.occurrences(param))?;
+                body.satisfies_linearity(policy)
+            },
+            Self::App(func, arg) => {
+                func.satisfies_linearity(policy)?;
+                arg.satisfies_linearity(policy)
+            },
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! Custom infix notations for displaying [Term]s.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::hash::Hash;
+
+use crate::Term;
+
+/// An infix operator that a free variable is displayed as, along with its precedence.
+/// 
+/// All infix operators are left-associative, and higher precedences bind more tightly.
+/// Regular application always binds more tightly than any infix operator.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Infix {
+    /// The symbol used to display the operator.
+    pub symbol: String,
+    /// The precedence of the operator.
+    pub precedence: usize,
+}
+
+/// A table of custom notations used by [Term::display_with_notation].
+/// 
+/// Each entry maps the identifier of a free variable to an [Infix] operator.
+/// Applications of that variable to exactly two arguments are then displayed infix, with parentheses added only where necessary.
+#[derive(Clone, Debug)]
+pub struct NotationTable<T> {
+    infix: HashMap<T, Infix>,
+}
+
+impl<T> NotationTable<T> {
+    /// Creates a new [NotationTable] with no notations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Eq + Hash> NotationTable<T> {
+    /// Displays the free variable with the specified identifier as an infix operator with the specified symbol and precedence.
+    /// 
+    /// If the identifier already had an [Infix] operator, it is replaced and returned.
+    pub fn insert_infix(&mut self, name: T, symbol: impl Into<String>, precedence: usize) -> Option<Infix> {
+        self.infix.insert(name, Infix {
+            symbol: symbol.into(),
+            precedence,
+        })
+    }
+
+    /// Returns the [Infix] operator for the free variable with the specified identifier, if there is one.
+    pub fn get_infix(&self, name: &T) -> Option<&Infix> {
+        self.infix.get(name)
+    }
+}
+
+impl<T> Default for NotationTable<T> {
+    fn default() -> Self {
+        Self {
+            infix: HashMap::default(),
+        }
+    }
+}
+
+impl<T: Display + Eq + Hash> Term<T> {
+    /// Returns a value that displays the [Term] like its [Display] `impl`ementation, but with applications of free variables in the [NotationTable] displayed infix.
+    /// 
+    /// Note that only free variables are displayed infix - a variable bound to a formal parameter with the same identifier is displayed as usual.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let mut table = NotationTable::new();
+    /// table.insert_infix("plus", "+", 6);
+    /// table.insert_infix("times", "*", 7);
+    /// 
+    /// assert_eq!(app!(var!(plus), var!(a), var!(b)).display_with_notation(&table).to_string(), "a + b");
+    /// assert_eq!(lambda!(plus a (times b c)).display_with_notation(&table).to_string(), "a + b * c");
+    /// assert_eq!(lambda!(times (plus a b) c).display_with_notation(&table).to_string(), "(a + b) * c");
+    /// assert_eq!(lambda!(plus (plus a b) c).display_with_notation(&table).to_string(), "a + b + c");
+    /// assert_eq!(lambda!(plus a (plus b c)).display_with_notation(&table).to_string(), "a + (b + c)");
+    /// assert_eq!(lambda!(plus (f a) b c).display_with_notation(&table).to_string(), "(f a + b) c");
+    /// assert_eq!(lambda!(λ plus. plus a b).display_with_notation(&table).to_string(), "λplus. plus a b");
+    /// ```
+    pub fn display_with_notation<'t>(&'t self, table: &'t NotationTable<T>) -> impl Display + 't {
+        WithNotation {
+            term: self,
+            table,
+            bound: Vec::new(),
+        }
+    }
+}
+
+struct WithNotation<'t, T> {
+    term: &'t Term<T>,
+    table: &'t NotationTable<T>,
+    bound: Vec<&'t T>,
+}
+
+impl<'t, T: Display + Eq + Hash> WithNotation<'t, T> {
+    fn with(&self, term: &'t Term<T>) -> Self {
+        Self {
+            term,
+            table: self.table,
+            bound: self.bound.clone(),
+        }
+    }
+
+    fn infix(&self, term: &'t Term<T>) -> Option<(&'t Infix, &'t Term<T>, &'t Term<T>)> {
+        let Term::App(func, right) = term else {
+            return None;
+        };
+        let Term::App(op, left) = func.as_ref() else {
+            return None;
+        };
+        match op.as_ref() {
+            Term::Var(name) if !self.bound.contains(&name) => self.table
+                .get_infix(name)
+                .map(|infix| (infix, left.as_ref(), right.as_ref())),
+            _ => None,
+        }
+    }
+
+    fn fmt_wrapped(&self, formatter: &mut Formatter<'_>, term: &'t Term<T>, parens: bool) -> FmtResult {
+        if parens {
+            write!(formatter, "({})", self.with(term))
+        } else {
+            write!(formatter, "{}", self.with(term))
+        }
+    }
+}
+
+impl<T: Display + Eq + Hash> Display for WithNotation<'_, T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self.term {
+            Term::Var(var) => write!(formatter, "{}", var),
+            Term::Abs(param, body) => {
+                let mut body = self.with(body);
+                body.bound.push(param);
+                write!(formatter, "λ{}. {}", param, body)
+            },
+            Term::App(func, arg) => match self.infix(self.term) {
+                Some((infix, left, right)) => {
+                    let left_parens = matches!(left, Term::Abs(_, _)) || self.infix(left).is_some_and(|(other, _, _)| other.precedence < infix.precedence);
+                    let right_parens = matches!(right, Term::Abs(_, _)) || self.infix(right).is_some_and(|(other, _, _)| other.precedence <= infix.precedence);
+                    self.fmt_wrapped(formatter, left, left_parens)?;
+                    write!(formatter, " {} ", infix.symbol)?;
+                    self.fmt_wrapped(formatter, right, right_parens)
+                },
+                None => {
+                    let func_parens = matches!(func.as_ref(), Term::Abs(_, _)) || self.infix(func).is_some();
+                    let arg_parens = matches!(arg.as_ref(), Term::Abs(_, _) | Term::App(_, _));
+                    self.fmt_wrapped(formatter, func, func_parens)?;
+                    write!(formatter, " ")?;
+                    self.fmt_wrapped(formatter, arg, arg_parens)
+                },
+            },
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Operator overloads for constructing [Term]s.
+
+use std::ops::Mul;
+
+use crate::Term;
+
+/// Applies the [Term] on the left to the [Term] on the right (see [Term::app]).
+/// 
+/// Multiplication is used since it is conventionally written as juxtaposition, just like application.
+/// It is also left-associative, so `f * a * b` applies `f` to `a` and then to `b`, just like `f a b`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// 
+/// let id = lambda!(λ x. x);
+/// assert_eq!(id.clone() * var!(y), app!(id, var!(y)));
+/// assert_eq!(var!(f) * var!(a) * var!(b), lambda!(f a b));
+/// ```
+impl<T> Mul for Term<T> {
+    type Output = Self;
+
+    fn mul(self, arg: Self) -> Self::Output {
+        Self::app(self, arg)
+    }
+}
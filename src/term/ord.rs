@@ -0,0 +1,76 @@
+//! A total order on [Term]s up to α-equivalence.
+
+use std::cmp::Ordering;
+
+use crate::LocalNamelessTerm;
+use crate::Term;
+use crate::Var;
+
+/// A wrapper around a [Term] that is compared up to α-equivalence, allowing [Term]s to be stored in ordered collections such as [BTreeSet](std::collections::BTreeSet).
+/// 
+/// Two [OrdTerm]s are equal if and only if their [Term]s are α-equivalent.
+/// Otherwise, they are ordered by their locally nameless representations (see [LocalNamelessTerm]), where:
+/// - Variables are less than abstractions, which are less than applications
+/// - Bound variables are less than free variables, and are ordered by their De Bruijn indices
+/// - Free variables are ordered by their identifiers
+/// - Abstractions are ordered by their bodies, ignoring their formal parameters
+/// - Applications are ordered by their functions, and then by their arguments
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::collections::BTreeSet;
+/// 
+/// use lamb::*;
+/// 
+/// let mut set = BTreeSet::new();
+/// set.insert(OrdTerm(lambda!(λ x. x)));
+/// set.insert(OrdTerm(lambda!(λ y. y)));
+/// assert_eq!(set.len(), 1);
+/// 
+/// set.insert(OrdTerm(lambda!(λ x y. x)));
+/// set.insert(OrdTerm(lambda!(λ x y. y)));
+/// assert_eq!(set.len(), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct OrdTerm<T: Ord>(pub Term<T>);
+
+impl<T: Clone + Ord> Ord for OrdTerm<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        LocalNamelessTerm::from(&self.0).nameless_cmp(&LocalNamelessTerm::from(&other.0))
+    }
+}
+
+impl<T: Clone + Ord> PartialOrd for OrdTerm<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Clone + Ord> Eq for OrdTerm<T> {}
+
+impl<T: Clone + Ord> PartialEq for OrdTerm<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord> LocalNamelessTerm<T> {
+    fn nameless_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Var(var), Self::Var(other)) => match (var, other) {
+                (Var::Bound(index), Var::Bound(other)) => index.cmp(other),
+                (Var::Bound(_), Var::Free(_)) => Ordering::Less,
+                (Var::Free(_), Var::Bound(_)) => Ordering::Greater,
+                (Var::Free(var), Var::Free(other)) => var.cmp(other),
+            },
+            (Self::Var(_), _) => Ordering::Less,
+            (_, Self::Var(_)) => Ordering::Greater,
+            (Self::Abs(_, body), Self::Abs(_, other)) => body.nameless_cmp(other),
+            (Self::Abs(_, _), _) => Ordering::Less,
+            (_, Self::Abs(_, _)) => Ordering::Greater,
+            (Self::App(func, arg), Self::App(other_func, other_arg)) => func.nameless_cmp(other_func)
+                .then_with(|| arg.nameless_cmp(other_arg)),
+        }
+    }
+}
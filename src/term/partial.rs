@@ -0,0 +1,62 @@
+//! Partial evaluation of [Term]s, contracting only the β-redexes that do not depend on free variables.
+
+use std::mem;
+
+use crate::LocalNamelessTerm;
+use crate::Term;
+use crate::Var;
+
+impl<T: Clone + Eq> Term<T> {
+    /// Returns a version of the [Term] in which every β-redex whose function is closed (i.e. has no free variables) has been contracted, leaving all other β-redexes alone.
+    /// 
+    /// This precomputes the "static" parts of the [Term] - a closed function behaves the same regardless of what its free variables are later bound to, so applying it early is always safe.
+    /// The argument of such a β-redex may itself contain free variables, since the function can only move it around.
+    /// Variables bound by an abstraction outside the function count as free in it, so `λy. (λx. y) a` is left as-is.
+    /// 
+    /// The [Term] is traversed once from the innermost β-redexes outwards, and β-redexes created by contracting others are not contracted in the same pass.
+    /// This guarantees termination even for [Term]s like `(λx. x x) (λx. x x)`, and calling this function again can make further progress.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// assert_eq!(lambda!((λ x. x) a).partial_evaluate(), lambda!(a));
+    /// 
+    /// let term = lambda!(λ f. (λ x. f x) a ((λ y. y) b));
+    /// assert_eq!(term.partial_evaluate(), lambda!(λ f. (λ x. f x) a b));
+    /// ```
+    pub fn partial_evaluate(&self) -> Term<T> {
+        let mut local_nameless = LocalNamelessTerm::from(self);
+        local_nameless.partial_evaluate_in_place();
+        (&local_nameless).try_into().unwrap()
+    }
+}
+
+impl<T: Clone> LocalNamelessTerm<T> {
+    fn partial_evaluate_in_place(&mut self) {
+        match self {
+            Self::Var(_) => (),
+            Self::Abs(_, body) => body.partial_evaluate_in_place(),
+            Self::App(func, arg) => {
+                func.partial_evaluate_in_place();
+                arg.partial_evaluate_in_place();
+                if let Self::Abs(_, body) = func.as_mut() {
+                    if body.is_closed_at(1) {
+                        body.substitute_index(0, arg);
+                        *self = mem::replace(body.as_mut(), Self::var(Var::Bound(0)));
+                    }
+                }
+            },
+        }
+    }
+
+    fn is_closed_at(&self, depth: usize) -> bool {
+        match self {
+            Self::Var(Var::Bound(index)) => *index < depth,
+            Self::Var(Var::Free(_)) => false,
+            Self::Abs(_, body) => body.is_closed_at(depth + 1),
+            Self::App(func, arg) => func.is_closed_at(depth) && arg.is_closed_at(depth),
+        }
+    }
+}
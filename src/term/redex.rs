@@ -0,0 +1,77 @@
+//! Locating β-redexes in [Term]s.
+
+use crate::Direction;
+use crate::Term;
+
+impl<T> Term<T> {
+    /// Returns the paths to the outermost β-redexes in the [Term], from left to right.
+    /// 
+    /// No β-redex returned is contained in another, so they are independent of one another and could be contracted simultaneously.
+    /// β-redexes nested inside those returned are not included.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!(f ((λ x. x) a) ((λ y. y) b));
+    /// assert_eq!(term.independent_redexes(), vec![
+    ///     vec![Direction::Func, Direction::Arg],
+    ///     vec![Direction::Arg],
+    /// ]);
+    /// 
+    /// let term = lambda!((λ x. x) ((λ y. y) b));
+    /// assert_eq!(term.independent_redexes(), vec![vec![]]);
+    /// ```
+    pub fn independent_redexes(&self) -> Vec<Vec<Direction>> {
+        let mut redexes = Vec::new();
+        self.independent_redexes_at(&mut Vec::new(), &mut redexes);
+        redexes
+    }
+
+    /// Returns the depth of the leftmost, outermost β-redex in the [Term], which is the one that [Normal](crate::Normal) β-reduction would contract next.
+    /// 
+    /// The depth is the number of abstractions and applications that enclose the β-redex, so a [Term] that is itself a β-redex has a depth of 0.
+    /// If the [Term] is in β-normal form, this returns [None].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// assert_eq!(lambda!((λ x. x) y).leftmost_redex_depth(), Some(0));
+    /// assert_eq!(lambda!(λ y. (λ x. x) y).leftmost_redex_depth(), Some(1));
+    /// assert_eq!(lambda!(f a ((λ x. x) b)).leftmost_redex_depth(), Some(1));
+    /// assert_eq!(lambda!(λ x. f x).leftmost_redex_depth(), None);
+    /// ```
+    pub fn leftmost_redex_depth(&self) -> Option<usize> {
+        match self {
+            Self::Var(_) => None,
+            Self::Abs(_, body) => body.leftmost_redex_depth().map(|depth| depth + 1),
+            Self::App(func, _) if matches!(func.as_ref(), Self::Abs(_, _)) => Some(0),
+            Self::App(func, arg) => func.leftmost_redex_depth()
+                .or_else(|| arg.leftmost_redex_depth())
+                .map(|depth| depth + 1),
+        }
+    }
+
+    fn independent_redexes_at(&self, path: &mut Vec<Direction>, redexes: &mut Vec<Vec<Direction>>) {
+        match self {
+            Self::Var(_) => (),
+            Self::Abs(_, body) => {
+                path.push(Direction::Body);
+                body.independent_redexes_at(path, redexes);
+                path.pop();
+            },
+            Self::App(func, _) if matches!(func.as_ref(), Self::Abs(_, _)) => redexes.push(path.clone()),
+            Self::App(func, arg) => {
+                path.push(Direction::Func);
+                func.independent_redexes_at(path, redexes);
+                path.pop();
+                path.push(Direction::Arg);
+                arg.independent_redexes_at(path, redexes);
+                path.pop();
+            },
+        }
+    }
+}
@@ -7,39 +7,242 @@ use std::hash::Hash;
 use std::iter;
 use std::ops::Deref;
 use std::ops::DerefMut;
+use std::sync::mpsc::Sender;
 
 use crate::Term;
 
+pub mod fuel;
+pub use fuel::*;
+
+pub mod graph;
+pub use graph::*;
+
+pub mod lazy;
+
+pub mod nontermination;
+pub use nontermination::*;
+
 pub mod normal;
 pub use normal::*;
 
+pub mod parallel;
+pub use parallel::*;
+
+pub mod random;
+pub use random::*;
+
+pub mod reduction;
+pub use reduction::*;
+
+pub mod right_to_left;
+pub use right_to_left::*;
+
+pub mod spine_strict;
+pub use spine_strict::*;
+
+pub mod stats;
+pub use stats::*;
+
+mod trace;
+pub(crate) use trace::*;
+
+pub mod whnf;
+pub use whnf::*;
+
 /// Represents a β-reduction strategy for [Term]s.
 /// 
 /// The only associated function required when `impl`ementing this trait is [BetaReduce::beta_reduce_step].
 /// The other associated functions have default implementations that rely on [BetaReduce::beta_reduce_step], but can be overridden with custom implementations if necessary.
+/// 
+/// This trait can be used as a trait object (such as `Box<dyn BetaReduce<T>>`), although [BetaReduce::beta_reduce_while] is unavailable on trait objects since it is generic.
 pub trait BetaReduce<T> {
     /// Performs one step of β-reduction on the [Term] in-place, and returns a value indicating whether reduction was performed or not.
     /// 
     /// Implementations of this function should return `false` if the [Term] is in β-normal form (i.e. no more β-reduction is possible).
     fn beta_reduce_step(&self, term: &mut Term<T>) -> bool;
 
+    /// Performs one step of β-reduction on the [Term] in-place (see [BetaReduce::beta_reduce_step]), and returns the path to the subterm that was β-reduced, or [None] if the [Term] is in β-normal form.
+    /// 
+    /// If a step contracts more than one β-redex, this is the path to the smallest subterm containing all of them.
+    /// 
+    /// All strategies in this crate override this to report the exact location of the contracted β-redexes.
+    /// The default implementation is only a fallback for other strategies, which infers the path by comparing the [Term] before and after the step (cloning it beforehand), and returns the path to the smallest subterm containing every change.
+    /// This is usually the path to the β-redex itself, but it may be a path inside the β-redex if its contractum happens to resemble it (such as `(λx. f x) f` becoming `f f`).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let mut term = LocalNamelessTerm::from(&lambda!((λ x. x) y));
+    /// assert_eq!(Normal::default().beta_reduce_step_located(&mut term), Some(vec![]));
+    /// assert_eq!(Normal::default().beta_reduce_step_located(&mut term), None);
+    /// 
+    /// let mut term = LocalNamelessTerm::from(&lambda!(λ f. f ((λ x. x) y)));
+    /// assert_eq!(Normal::default().beta_reduce_step_located(&mut term), Some(vec![Direction::Body, Direction::Arg]));
+    /// 
+    /// // The contractum resembles the β-redex, but the exact location is still reported
+    /// let mut term = LocalNamelessTerm::from(&lambda!(g ((λ x. f x) f)));
+    /// assert_eq!(Normal::default().beta_reduce_step_located(&mut term), Some(vec![Direction::Arg]));
+    /// ```
+    fn beta_reduce_step_located(&self, term: &mut Term<T>) -> Option<Vec<Direction>>
+    where
+        T: Clone + PartialEq, {
+            let old = term.clone();
+            if !self.beta_reduce_step(term) {
+                return None;
+            }
+            let mut path = Vec::new();
+            let (mut old, mut new) = (&old, &*term);
+            loop {
+                match (old, new) {
+                    (Term::Abs(_, old_body), Term::Abs(_, new_body)) => {
+                        path.push(Direction::Body);
+                        (old, new) = (old_body, new_body);
+                    },
+                    (Term::App(old_func, old_arg), Term::App(new_func, new_arg)) if old_func == new_func => {
+                        path.push(Direction::Arg);
+                        (old, new) = (old_arg, new_arg);
+                    },
+                    (Term::App(old_func, old_arg), Term::App(new_func, new_arg)) if old_arg == new_arg => {
+                        path.push(Direction::Func);
+                        (old, new) = (old_func, new_func);
+                    },
+                    _ => break Some(path),
+                }
+            }
+        }
+
+    /// Performs one step of β-reduction on the [Term] in-place (see [BetaReduce::beta_reduce_step]), adding the number of substitutions and copied nodes it required to the [ReduceStats].
+    /// 
+    /// All strategies in this crate override this to count the work done by every β-redex they contract, at the point where it is contracted.
+    /// The default implementation only performs the step, without counting anything, since it has no way of knowing which β-redexes were contracted.
+    fn beta_reduce_step_with_stats(&self, term: &mut Term<T>, _stats: &mut ReduceStats) -> bool {
+        self.beta_reduce_step(term)
+    }
+
+    /// Attempts to fully β-reduce the [Term] in-place until it reaches β-normal form, and returns the number of reduction steps performed along with [ReduceStats] about them.
+    /// 
+    /// The substitutions and copied nodes are counted by [BetaReduce::beta_reduce_step_with_stats], while the largest size is measured after every step.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let mut duplicating = LocalNamelessTerm::from(&lambda!((λ x. x x) (λ a. f a)));
+    /// let (count, stats) = Normal::default().beta_reduce_with_stats(&mut duplicating);
+    /// assert_eq!(count, 2);
+    /// assert_eq!(stats.substitutions, 3);
+    /// assert_eq!(stats.nodes_copied, 12);
+    /// 
+    /// let mut discarding = LocalNamelessTerm::from(&lambda!((λ x. y) (λ a. f a)));
+    /// let (_, stats) = Normal::default().beta_reduce_with_stats(&mut discarding);
+    /// assert_eq!(stats.nodes_copied, 0);
+    /// assert_eq!(stats.max_size, 7);
+    /// 
+    /// // Normal-order steps may contract several β-redexes at once, each of which is counted
+    /// let mut independent = LocalNamelessTerm::from(&lambda!(f ((λ x. x x) a) ((λ y. y y) b)));
+    /// let (count, stats) = Normal::default().beta_reduce_with_stats(&mut independent);
+    /// assert_eq!(count, 1);
+    /// assert_eq!(stats.substitutions, 4);
+    /// ```
+    fn beta_reduce_with_stats(&self, term: &mut Term<T>) -> (usize, ReduceStats) {
+        let mut stats = ReduceStats {
+            max_size: term.size(),
+            ..ReduceStats::default()
+        };
+        let mut count = 0;
+        while self.beta_reduce_step_with_stats(term, &mut stats) {
+            count += 1;
+            stats.max_size = stats.max_size.max(term.size());
+        }
+        (count, stats)
+    }
+
     /// Attempts to fully β-reduce the [Term] in-place until it reaches β-normal form, and returns the number of reduction steps performed.
+    /// 
+    /// The default implementation calls [BetaReduce::beta_reduce_fueled] with [Fuel::unlimited].
     fn beta_reduce(&self, term: &mut Term<T>) -> usize {
-        iter::from_fn(|| self.beta_reduce_step(term).then_some(())).count()
+        self.beta_reduce_fueled(term, &mut Fuel::unlimited())
+    }
+
+    /// Attempts to β-reduce the [Term] in-place until it reaches β-normal form or the [Fuel] runs out, and returns the number of reduction steps performed.
+    /// 
+    /// Every step consumes one unit of [Fuel], so the remaining [Fuel] can be passed on to further β-reduction.
+    /// The default implementations of [BetaReduce::beta_reduce], [BetaReduce::beta_reduce_limit], and [BetaReduce::beta_reduce_while] all call this, so overriding it changes how all of them β-reduce, while overriding any of them does not affect the others.
+    /// Implementations that override this must not perform more steps than the [Fuel] allows.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::cell::Cell;
+    /// 
+    /// use lamb::*;
+    /// 
+    /// #[derive(Default)]
+    /// struct Counted {
+    ///     loops: Cell<usize>,
+    /// }
+    /// 
+    /// impl<T: Clone> BetaReduce<Var<T>> for Counted {
+    ///     fn beta_reduce_step(&self, term: &mut LocalNamelessTerm<T>) -> bool {
+    ///         Normal::default().beta_reduce_step(term)
+    ///     }
+    /// 
+    ///     // A custom loop that keeps count of how often it is entered
+    ///     fn beta_reduce_fueled(&self, term: &mut LocalNamelessTerm<T>, fuel: &mut Fuel) -> usize {
+    ///         self.loops.set(self.loops.get() + 1);
+    ///         let mut count = 0;
+    ///         while !fuel.is_empty() && self.beta_reduce_step(term) {
+    ///             fuel.consume();
+    ///             count += 1;
+    ///         }
+    ///         count
+    ///     }
+    /// }
+    /// 
+    /// let strategy = Counted::default();
+    /// 
+    /// let mut term = LocalNamelessTerm::from(&lambda!((λ x. x x) (λ x. x x)));
+    /// assert_eq!(strategy.beta_reduce_limit(&mut term, 5), 5);
+    /// assert_eq!(strategy.loops.get(), 1);
+    /// 
+    /// let mut term = LocalNamelessTerm::from(&lambda!((λ x. x) ((λ y. y) z)));
+    /// assert_eq!(strategy.beta_reduce(&mut term), 2);
+    /// assert_eq!(strategy.loops.get(), 2);
+    /// 
+    /// // One unit of fuel is passed in for each step
+    /// let mut term = LocalNamelessTerm::from(&lambda!((λ x. x) ((λ y. y) z)));
+    /// assert_eq!(strategy.beta_reduce_while(&mut term, |_, count| count < 5), 2);
+    /// assert_eq!(strategy.loops.get(), 5);
+    /// 
+    /// let mut term = LocalNamelessTerm::from(&lambda!((λ x. x) ((λ y. y) z)));
+    /// let mut fuel = Fuel::new(10);
+    /// assert_eq!(strategy.beta_reduce_fueled(&mut term, &mut fuel), 2);
+    /// assert_eq!(fuel.remaining(), Some(8));
+    /// ```
+    fn beta_reduce_fueled(&self, term: &mut Term<T>, fuel: &mut Fuel) -> usize {
+        iter::from_fn(|| (!fuel.is_empty() && self.beta_reduce_step(term) && fuel.consume()).then_some(())).count()
     }
 
     /// Attempts to β-reduce the [Term] in-place until it reaches β-normal form or the predicate returns `false`, and returns the number of reduction steps performed.
+    /// 
+    /// The default implementation calls [BetaReduce::beta_reduce_fueled] with one unit of [Fuel] at a time, checking the predicate before each step.
     fn beta_reduce_while<P>(&self, term: &mut Term<T>, mut predicate: P) -> usize
     where
+        Self: Sized,
         P: FnMut(&Term<T>, usize) -> bool, {
-            (0..).into_iter()
-                .take_while(|count| predicate(term, *count) && self.beta_reduce_step(term))
+            (0..)
+                .take_while(|count| predicate(term, *count) && self.beta_reduce_fueled(term, &mut Fuel::new(1)) == 1)
                 .count()
         }
     
     /// Attempts to β-reduce the [Term] in-place until it reaches β-normal form or the number of reduction steps performed crosses a limit, and returns the latter.
+    /// 
+    /// The default implementation calls [BetaReduce::beta_reduce_fueled] with [Fuel::new].
     fn beta_reduce_limit(&self, term: &mut Term<T>, limit: usize) -> usize {
-        self.beta_reduce_while(term, |_, count| count < limit)
+        self.beta_reduce_fueled(term, &mut Fuel::new(limit))
     }
 }
 
@@ -55,6 +258,17 @@ pub enum Var<T> {
     Free(T),
 }
 
+/// A step from a [Term] to one of its immediate subterms, used to describe the location of a subterm.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    /// The body of an abstraction.
+    Body,
+    /// The function (left-hand side) of an application.
+    Func,
+    /// The argument (right-hand side) of an application.
+    Arg,
+}
+
 /// Represents possible errors that can occur when converting a [LocalNamelessTerm] to a regular (classic) [Term].
 /// 
 /// In most cases, there is no possibility of these errors occurring, as all functions that produce or modify [LocalNamelessTerm]s do so in a controlled, deterministic fashion.
@@ -73,12 +287,56 @@ pub enum LocalNamelessError {
     InvalidAbsParam(usize),
 }
 
+/// Represents possible errors that can occur when replacing a [LocalNamelessTerm]'s free variables with bindings using [LocalNamelessTerm::rebind_checked].
+#[derive(Debug)]
+pub enum RebindError<T> {
+    /// A binding refers to itself, either directly or through other bindings, so its expansion would never terminate.
+    /// 
+    /// This contains the name of the binding that was encountered again while it was being expanded.
+    CyclicBind(T),
+}
+
 /// The locally nameless representation of a [Term].
 /// 
 /// Variables are wrapped in [Var]s, which avoids the need for α-conversion when substituting or β-reducing [Term]s.
 /// However, fully β-reducing a [Term] using this implementation requires two extra steps - converting the [Term] to a [LocalNamelessTerm] and back.
 pub type LocalNamelessTerm<T> = Term<Var<T>>;
 
+impl<T> LocalNamelessTerm<T> {
+    /// Returns the largest De Bruijn index of a bound variable that escapes all abstractions in the [LocalNamelessTerm], relative to the [LocalNamelessTerm] itself.
+    /// 
+    /// Adding one to the result gives the number of abstractions that must enclose the [LocalNamelessTerm] for it to be locally closed.
+    /// If the [LocalNamelessTerm] is already locally closed (i.e. all of its bound variables refer to abstractions within it), this returns [None].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = LocalNamelessTerm::from(&lambda!(λ x. λ y. x));
+    /// assert_eq!(term.max_free_index(), None);
+    /// 
+    /// let LocalNamelessTerm::Abs(_, body) = &term else { unreachable!() };
+    /// let LocalNamelessTerm::Abs(_, body) = body.as_ref() else { unreachable!() };
+    /// assert_eq!(body.as_ref(), &LocalNamelessTerm::var(Var::Bound(1)));
+    /// assert_eq!(body.max_free_index(), Some(1));
+    /// 
+    /// assert_eq!(LocalNamelessTerm::<&str>::var(Var::Bound(0)).max_free_index(), Some(0));
+    /// ```
+    pub fn max_free_index(&self) -> Option<usize> {
+        self.max_free_index_at(0)
+    }
+
+    fn max_free_index_at(&self, depth: usize) -> Option<usize> {
+        match self {
+            Self::Var(Var::Bound(index)) => index.checked_sub(depth),
+            Self::Var(Var::Free(_)) => None,
+            Self::Abs(_, body) => body.max_free_index_at(depth + 1),
+            Self::App(func, arg) => func.max_free_index_at(depth).max(arg.max_free_index_at(depth)),
+        }
+    }
+}
+
 impl<T: Clone> LocalNamelessTerm<T> {
     /// Fully β-reduces the [LocalNamelessTerm] in-place using the specified [BetaReduce] `impl`ementation.
     pub fn beta_reduce<B: BetaReduce<Var<T>>>(&mut self, reducer: &B) -> usize {
@@ -103,18 +361,72 @@ impl<T: Clone> LocalNamelessTerm<T> {
         reducer.beta_reduce_step(self)
     }
 
-    fn open(&mut self, depth: usize, replacement: &Self) {
+    /// Replaces the bound variable with the specified De Bruijn index in-place with a replacement [LocalNamelessTerm], and adjusts the De Bruijn indices of other bound variables accordingly.
+    /// 
+    /// This is the substitution primitive for De Bruijn indices, and is used by the built-in [BetaReduce] `impl`ementations to contract β-redexes.
+    /// The index is relative to the [LocalNamelessTerm] itself, and increases by one inside each abstraction.
+    /// The replacement's own bound variables that escape it are shifted as it is moved under abstractions, so that they keep referring to the same abstractions.
+    /// Bound variables with greater indices are decremented, as the abstraction that bound the replaced variable is assumed to be removed.
+    /// 
+    /// # Examples
+    /// 
+    /// Contracting a β-redex is equivalent to substituting the argument for index `0` in the abstraction's body.
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let redex = LocalNamelessTerm::from(&lambda!((λ x. f x (λ y. x y)) a));
+    /// 
+    /// let mut reduced = redex.clone();
+    /// reduced.beta_reduce_step(&Normal::default());
+    /// 
+    /// let LocalNamelessTerm::App(func, arg) = redex else { unreachable!() };
+    /// let LocalNamelessTerm::Abs(_, mut body) = *func else { unreachable!() };
+    /// body.substitute_index(0, &arg);
+    /// 
+    /// assert_eq!(*body, reduced);
+    /// assert_eq!(Term::try_from(&reduced).unwrap(), lambda!(f a (λ y. a y)));
+    /// ```
+    /// 
+    /// Greater indices refer to abstractions further outside the [LocalNamelessTerm], and the replacement is only shifted by the abstractions it is moved under.
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let mut term = LocalNamelessTerm::<&str>::var(Var::Bound(1));
+    /// term.substitute_index(1, &LocalNamelessTerm::var(Var::Bound(0)));
+    /// assert_eq!(term, LocalNamelessTerm::var(Var::Bound(0)));
+    /// 
+    /// // λ. 0 2 3
+    /// let mut term = LocalNamelessTerm::abs(Var::Free("x"), LocalNamelessTerm::app(
+    ///     LocalNamelessTerm::app(LocalNamelessTerm::var(Var::Bound(0)), LocalNamelessTerm::var(Var::Bound(2))),
+    ///     LocalNamelessTerm::var(Var::Bound(3)),
+    /// ));
+    /// term.substitute_index(1, &LocalNamelessTerm::var(Var::Bound(0)));
+    /// 
+    /// // λ. 0 1 2
+    /// assert_eq!(term, LocalNamelessTerm::abs(Var::Free("x"), LocalNamelessTerm::app(
+    ///     LocalNamelessTerm::app(LocalNamelessTerm::var(Var::Bound(0)), LocalNamelessTerm::var(Var::Bound(1))),
+    ///     LocalNamelessTerm::var(Var::Bound(2)),
+    /// )));
+    /// ```
+    pub fn substitute_index(&mut self, index: usize, replacement: &Self) {
+        self.substitute_under(index, 0, replacement);
+    }
+
+    // Tracks the number of abstractions entered separately from the target index, so that the replacement is shifted by exactly that many
+    fn substitute_under(&mut self, index: usize, binders: usize, replacement: &Self) {
         match self {
-            Self::Var(Var::Bound(index)) => match (*index).cmp(&depth) {
-                Ordering::Equal => *self = replacement.shifted(0, depth),
-                Ordering::Greater => *index -= 1,
+            Self::Var(Var::Bound(bound)) => match (*bound).cmp(&(index + binders)) {
+                Ordering::Equal => *self = replacement.shifted(0, binders),
+                Ordering::Greater => *bound -= 1,
                 Ordering::Less => (),
             },
             Self::Var(Var::Free(_)) => (),
-            Self::Abs(_, body) => body.open(depth + 1, replacement),
+            Self::Abs(_, body) => body.substitute_under(index, binders + 1, replacement),
             Self::App(func, arg) => {
-                func.open(depth, replacement);
-                arg.open(depth, replacement);
+                func.substitute_under(index, binders, replacement);
+                arg.substitute_under(index, binders, replacement);
             },
         }
     }
@@ -132,6 +444,47 @@ impl<T: Clone> LocalNamelessTerm<T> {
         }
     }
 
+    /// Converts the [LocalNamelessTerm] to a regular (classic) [Term], taking the identifiers of its formal parameters from a supply instead of using the stored ones.
+    /// 
+    /// Identifiers are taken from the supply in the order that the abstractions appear (outermost and leftmost first).
+    /// If the supply runs out, the remaining formal parameters keep their stored identifiers.
+    /// 
+    /// Note that identifiers from the supply are used as-is, so a supply containing the identifiers of free variables can cause them to be captured.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = LocalNamelessTerm::from(&lambda!(λ x. λ y. x (λ z. z y)));
+    /// assert_eq!(term.to_classic_with_names(&mut ["a", "b"].into_iter()).unwrap(), lambda!(λ a. λ b. a (λ z. z b)));
+    /// ```
+    pub fn to_classic_with_names(&self, supply: &mut impl Iterator<Item = T>) -> Result<Term<T>, LocalNamelessError> {
+        self.to_classic_supplied(supply, &mut VecDeque::new())
+    }
+
+    fn to_classic_supplied(&self, supply: &mut impl Iterator<Item = T>, vars: &mut VecDeque<T>) -> Result<Term<T>, LocalNamelessError> {
+        match self {
+            Self::Var(Var::Bound(index)) => match vars.get(*index) {
+                Some(var) => Ok(Term::var(var.clone())),
+                None => Err(LocalNamelessError::InvalidVarIndex(*index)),
+            },
+            Self::Var(Var::Free(var)) => Ok(Term::var(var.clone())),
+            Self::Abs(param, body) => {
+                let param = match (supply.next(), param) {
+                    (Some(param), _) => param,
+                    (None, Var::Free(param)) => param.clone(),
+                    (None, Var::Bound(index)) => return Err(LocalNamelessError::InvalidAbsParam(*index)),
+                };
+                vars.push_front(param.clone());
+                let term = Term::abs(param, body.to_classic_supplied(supply, vars)?);
+                vars.pop_front();
+                Ok(term)
+            },
+            Self::App(func, arg) => Ok(Term::app(func.to_classic_supplied(supply, vars)?, arg.to_classic_supplied(supply, vars)?)),
+        }
+    }
+
     fn to_classic<'t>(&'t self, vars: &mut VecDeque<&'t T>) -> Result<Term<T>, LocalNamelessError> {
         match self {
             Self::Var(Var::Bound(index)) => match vars.get(*index) {
@@ -153,33 +506,136 @@ impl<T: Clone> LocalNamelessTerm<T> {
     }
 }
 
+impl<T: Eq> LocalNamelessTerm<T> {
+    /// Checks whether two [LocalNamelessTerm]s are equal while disregarding the identifiers of their formal parameters.
+    /// 
+    /// Since bound variables are represented as De Bruijn indices, this amounts to checking whether their classic counterparts are α-equivalent.
+    pub(crate) fn nameless_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Var(var), Self::Var(other)) => var == other,
+            (Self::Abs(_, body), Self::Abs(_, other)) => body.nameless_eq(other),
+            (Self::App(func, arg), Self::App(other_func, other_arg)) => func.nameless_eq(other_func) && arg.nameless_eq(other_arg),
+            _ => false,
+        }
+    }
+}
+
 impl<T: Clone + Eq + Hash> LocalNamelessTerm<T> {
     /// Replaces the [LocalNamelessTerm]'s free variables in-place with the specified bindings.
     /// 
     /// Free variables that are not part of the provided bindings are left untouched.
-    pub fn rebind<'t>(&'t mut self, binds: &mut HashMap<T, Self>) {
+    /// Returns the number of free variables that were replaced.
+    pub fn rebind(&mut self, binds: &mut HashMap<T, Self>) -> usize {
         match self {
-            Self::Var(Var::Bound(_)) => (),
-            Self::Var(Var::Free(var)) => if let Some(term) = binds.get(var) {
-                *self = term.clone();
+            Self::Var(Var::Bound(_)) => 0,
+            Self::Var(Var::Free(var)) => match binds.get(var) {
+                Some(term) => {
+                    *self = term.clone();
+                    1
+                },
+                None => 0,
             },
             Self::Abs(_, body) => body.rebind(binds),
+            Self::App(func, arg) => func.rebind(binds) + arg.rebind(binds),
+        }
+    }
+
+    /// Replaces the [LocalNamelessTerm]'s free variables in-place with the specified bindings, repeating this until no free variables with matching bindings remain.
+    /// 
+    /// Unlike [LocalNamelessTerm::rebind], this also expands free variables inside the bindings themselves.
+    /// If a binding refers to itself (either directly or through other bindings), its expansion would never terminate, so a [RebindError::CyclicBind] is returned instead.
+    /// The [LocalNamelessTerm] may be partially rebound if an error is returned.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::collections::HashMap;
+    /// 
+    /// use lamb::*;
+    /// 
+    /// let binds = HashMap::from([
+    ///     ("a", LocalNamelessTerm::from(&lambda!(λ x. b x))),
+    ///     ("b", LocalNamelessTerm::from(&lambda!(λ x. a x))),
+    ///     ("c", LocalNamelessTerm::from(&lambda!(λ x. x))),
+    /// ]);
+    /// 
+    /// let mut term = LocalNamelessTerm::from(&lambda!(c y));
+    /// assert!(term.rebind_checked(&binds).is_ok());
+    /// 
+    /// let mut term = LocalNamelessTerm::from(&lambda!(a y));
+    /// assert!(matches!(term.rebind_checked(&binds), Err(RebindError::CyclicBind("a"))));
+    /// ```
+    pub fn rebind_checked(&mut self, binds: &HashMap<T, Self>) -> Result<(), RebindError<T>> {
+        self.rebind_checked_with(binds, &mut Vec::new())
+    }
+
+    fn rebind_checked_with<'b>(&mut self, binds: &'b HashMap<T, Self>, expanding: &mut Vec<&'b T>) -> Result<(), RebindError<T>> {
+        match self {
+            Self::Var(Var::Bound(_)) => Ok(()),
+            Self::Var(Var::Free(var)) => match binds.get_key_value(var) {
+                Some((name, _)) if expanding.contains(&name) => Err(RebindError::CyclicBind(name.clone())),
+                Some((name, term)) => {
+                    let mut term = term.clone();
+                    expanding.push(name);
+                    term.rebind_checked_with(binds, expanding)?;
+                    expanding.pop();
+                    *self = term;
+                    Ok(())
+                },
+                None => Ok(()),
+            },
+            Self::Abs(_, body) => body.rebind_checked_with(binds, expanding),
             Self::App(func, arg) => {
-                func.rebind(binds);
-                arg.rebind(binds);
+                func.rebind_checked_with(binds, expanding)?;
+                arg.rebind_checked_with(binds, expanding)
             },
         }
     }
+
+    fn has_free_in<V>(&self, binds: &HashMap<T, V>) -> bool {
+        match self {
+            Self::Var(Var::Bound(_)) => false,
+            Self::Var(Var::Free(var)) => binds.contains_key(var),
+            Self::Abs(_, body) => body.has_free_in(binds),
+            Self::App(func, arg) => func.has_free_in(binds) || arg.has_free_in(binds),
+        }
+    }
+}
+
+impl<T: Clone + Eq> LocalNamelessTerm<T> {
+    /// Converts a classic [Term] to a [LocalNamelessTerm] like its [From] `impl`ementation, but using a caller-provided buffer to keep track of formal parameters.
+    /// 
+    /// The buffer is cleared before use, so it can be reused across many conversions to avoid allocating a new one each time.
+    /// Since it holds references to the formal parameters of the [Term]s being converted, those [Term]s must outlive it.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::collections::VecDeque;
+    /// 
+    /// use lamb::*;
+    /// 
+    /// let terms = [lambda!(λ x y. x y z), lambda!((λ x. x) (λ x y. y))];
+    /// let mut scratch = VecDeque::new();
+    /// for term in &terms {
+    ///     assert_eq!(LocalNamelessTerm::from_with_scratch(term, &mut scratch), LocalNamelessTerm::from(term));
+    /// }
+    /// ```
+    pub fn from_with_scratch<'t>(classic: &'t Term<T>, scratch: &mut VecDeque<&'t T>) -> Self {
+        scratch.clear();
+        classic.to_local_nameless(scratch)
+    }
 }
 
 impl<T: Clone + Eq> From<&Term<T>> for LocalNamelessTerm<T> {
     fn from(classic: &Term<T>) -> Self {
-        classic.to_local_nameless(&mut VecDeque::new())
+        Self::from_with_scratch(classic, &mut VecDeque::new())
     }
 }
 
 /// A wrapper around a β-reduced [Term], storing along with it the number of reduction steps performed.
 #[derive(Debug)]
+#[must_use]
 pub struct ReducedTerm<T> {
     /// The number of β-reduction steps performed when β-reducing the [Term].
     pub count: usize,
@@ -187,6 +643,28 @@ pub struct ReducedTerm<T> {
     pub term: Term<T>,
 }
 
+impl<T> ReducedTerm<T> {
+    /// Consumes the [ReducedTerm], returning the β-reduced [Term].
+    pub fn into_term(self) -> Term<T> {
+        self.term
+    }
+
+    /// Consumes the [ReducedTerm], returning the number of β-reduction steps performed and the β-reduced [Term].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let (count, term) = lambda!((λ x. x) ((λ y. y) z)).beta_reduced(&Normal::default()).into_parts();
+    /// assert_eq!(count, 2);
+    /// assert_eq!(term, var!(z));
+    /// ```
+    pub fn into_parts(self) -> (usize, Term<T>) {
+        (self.count, self.term)
+    }
+}
+
 impl<T> AsRef<Term<T>> for ReducedTerm<T> {
     fn as_ref(&self) -> &Term<T> {
         &self.term
@@ -238,6 +716,149 @@ impl<T: Clone + Eq> Term<T> {
         }
     }
 
+    /// Returns a fully β-reduced version of the [Term] wrapped in a [ReducedTerm] using the specified [BetaReduce] `impl`ementation, reporting progress along the way.
+    /// 
+    /// After every β-reduction step, the number of steps performed so far is sent through the [Sender].
+    /// This allows another thread (such as a UI thread) to monitor long-running β-reductions.
+    /// If the receiving end has been dropped, β-reduction continues regardless.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::sync::mpsc;
+    /// 
+    /// use lamb::*;
+    /// 
+    /// let (tx, rx) = mpsc::channel();
+    /// let reduced = lambda!((λ x. x) ((λ y. y) ((λ z. z) w))).beta_reduced_with_progress(&Normal::default(), tx);
+    /// assert_eq!(reduced.count, 3);
+    /// assert_eq!(rx.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    pub fn beta_reduced_with_progress<B: BetaReduce<Var<T>>>(&self, reducer: &B, progress: Sender<usize>) -> ReducedTerm<T> {
+        let mut local_nameless = LocalNamelessTerm::from(self);
+        let mut count = 0;
+        while local_nameless.beta_reduce_step(reducer) {
+            count += 1;
+            // A disconnected receiver only means that nobody is monitoring progress anymore
+            let _ = progress.send(count);
+        }
+        ReducedTerm {
+            count,
+            term: (&local_nameless).try_into().unwrap(),
+        }
+    }
+
+    /// Returns the β-normal form of the [Term] if it can be reached within a certain number of β-reduction steps, or [None] otherwise.
+    /// 
+    /// This always uses the [Normal] strategy, which is guaranteed to reach the β-normal form of any [Term] that has one (given enough steps).
+    /// Therefore, there is no benefit to trying other strategies if this returns [None] - either the [Term] has no β-normal form, or the limit is too low.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let id = lambda!(λ x. x);
+    /// assert_eq!(app!(id.clone(), var!(y)).normalize_robust(10), Some(var!(y)));
+    /// assert_eq!(id.normalize_robust(0), Some(id));
+    /// 
+    /// let omega = lambda!((λ x. x x) (λ x. x x));
+    /// assert_eq!(omega.normalize_robust(1000), None);
+    /// ```
+    pub fn normalize_robust(&self, limit: usize) -> Option<Term<T>> {
+        let mut local_nameless = LocalNamelessTerm::from(self);
+        // One step beyond the limit is attempted, since the β-normal form may be reached in exactly as many steps as the limit
+        let count = local_nameless.beta_reduce_while(|_, count| count <= limit, &Normal::default());
+        (count <= limit).then(|| (&local_nameless).try_into().unwrap())
+    }
+
+    /// β-reduces the [Term] using the specified [BetaReduce] `impl`ementation until it becomes α-equivalent to a target [Term], and returns the number of reduction steps performed.
+    /// 
+    /// If the [Term] reaches β-normal form or crosses the limit on reduction steps before becoming α-equivalent to the target, this returns [None].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!((λ x. x) y);
+    /// assert_eq!(term.reduce_until(&lambda!(y), 10, &Normal::default()), Some(1));
+    /// assert_eq!(term.reduce_until(&term, 10, &Normal::default()), Some(0));
+    /// assert_eq!(term.reduce_until(&lambda!(z), 10, &Normal::default()), None);
+    /// 
+    /// let term = lambda!((λ f x. f x) (λ y. y));
+    /// assert_eq!(term.reduce_until(&lambda!(λ a. (λ b. b) a), 10, &Normal::default()), Some(1));
+    /// assert_eq!(term.reduce_until(&lambda!(λ a. a), 1, &Normal::default()), None);
+    /// ```
+    pub fn reduce_until<B: BetaReduce<Var<T>>>(&self, target: &Term<T>, limit: usize, reducer: &B) -> Option<usize> {
+        let target = LocalNamelessTerm::from(target);
+        let mut local_nameless = LocalNamelessTerm::from(self);
+        let mut reached = false;
+        let count = local_nameless.beta_reduce_while(|term, count| {
+            reached = term.nameless_eq(&target);
+            !reached && count < limit
+        }, reducer);
+        reached.then_some(count)
+    }
+
+    /// Returns an iterator over snapshots of the [Term] as it is β-reduced step-by-step using the specified [BetaReduce] `impl`ementation.
+    /// 
+    /// Each item is the number of reduction steps performed so far, paired with the [Term] after those steps.
+    /// The first item is always the original [Term] with a count of zero, and the iterator ends once the [Term] reaches β-normal form.
+    /// 
+    /// If the [Term] has no β-normal form, the iterator is infinite, so consider limiting it using [Iterator::take].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!((λ x. x) ((λ y. y) ((λ z. z) w)));
+    /// let frames = term.reduction_frames(&Normal::default()).collect::<Vec<_>>();
+    /// assert_eq!(frames, vec![
+    ///     (0, term),
+    ///     (1, lambda!((λ y. y) ((λ z. z) w))),
+    ///     (2, lambda!((λ z. z) w)),
+    ///     (3, lambda!(w)),
+    /// ]);
+    /// ```
+    pub fn reduction_frames<'r, B>(&self, reducer: &'r B) -> impl Iterator<Item = (usize, Term<T>)> + 'r
+    where
+        T: 'r,
+        B: BetaReduce<Var<T>>, {
+            let mut local_nameless = LocalNamelessTerm::from(self);
+            iter::once((0, self.clone()))
+                .chain((1..).map_while(move |count| local_nameless.beta_reduce_step(reducer)
+                    .then(|| (count, (&local_nameless).try_into().unwrap()))))
+        }
+
+    /// Checks whether the [Term] is α-equivalent to another [Term], i.e. whether they are equal up to renaming of formal parameters.
+    /// 
+    /// Both [Term]s are converted to [LocalNamelessTerm]s and compared while disregarding the identifiers of their formal parameters.
+    /// Free variables are still compared by their identifiers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// assert!(lambda!(λ x. x).alpha_eq(&lambda!(λ y. y)));
+    /// assert!(lambda!(λ x. λ y. x (y z)).alpha_eq(&lambda!(λ a. λ b. a (b z))));
+    /// assert!(!lambda!(λ x. λ y. x).alpha_eq(&lambda!(λ x. λ y. y)));
+    /// 
+    /// // The innermost formal parameter shadows the outer one
+    /// assert!(lambda!(λ x. λ x. x).alpha_eq(&lambda!(λ a. λ b. b)));
+    /// assert!(!lambda!(λ x. λ x. x).alpha_eq(&lambda!(λ a. λ b. a)));
+    /// 
+    /// // Free variables are distinguished by their identifiers
+    /// assert!(!lambda!(λ x. a).alpha_eq(&lambda!(λ x. b)));
+    /// assert!(!lambda!(λ x. x).alpha_eq(&lambda!(λ y. x)));
+    /// assert!(lambda!(f (λ x. x f)).alpha_eq(&lambda!(f (λ g. g f))));
+    /// ```
+    pub fn alpha_eq(&self, other: &Term<T>) -> bool {
+        LocalNamelessTerm::from(self).nameless_eq(&LocalNamelessTerm::from(other))
+    }
+
     fn to_local_nameless<'t>(&'t self, vars: &mut VecDeque<&'t T>) -> LocalNamelessTerm<T> {
         match self {
             Self::Var(var) => match vars.iter().position(|&param| param == var) {
@@ -255,6 +876,46 @@ impl<T: Clone + Eq> Term<T> {
     }
 }
 
+impl<T: Clone + Eq + Hash> Term<T> {
+    /// Returns a version of the [Term] β-reduced up to a certain limit using the specified [BetaReduce] `impl`ementation, wrapped in a [ReducedTerm].
+    /// 
+    /// Before β-reduction, the [Term]'s free variables are replaced with their matching bindings (if they exist), much like in the REPL.
+    /// Since bindings may refer to other bindings, this is repeated until no free variables with matching bindings remain.
+    /// To prevent bindings that refer to themselves from expanding infinitely, at most one round of replacement is performed per binding.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::collections::HashMap;
+    /// 
+    /// use lamb::*;
+    /// 
+    /// let binds = HashMap::from([("i", lambda!(λ x. x)), ("k", lambda!(λ x y. i x))]);
+    /// 
+    /// let reduced = app!(var!(i), var!(z)).beta_reduced_with_binds(&binds, 100, &Normal::default());
+    /// assert_eq!(reduced.term, var!(z));
+    /// 
+    /// let reduced = app!(var!(k), var!(a), var!(b)).beta_reduced_with_binds(&binds, 100, &Normal::default());
+    /// assert_eq!(reduced.term, var!(a));
+    /// ```
+    pub fn beta_reduced_with_binds<B: BetaReduce<Var<T>>>(&self, binds: &HashMap<T, Term<T>>, limit: usize, reducer: &B) -> ReducedTerm<T> {
+        let mut binds: HashMap<_, _> = binds.iter()
+            .map(|(name, term)| (name.clone(), LocalNamelessTerm::from(term)))
+            .collect();
+        let mut local_nameless = LocalNamelessTerm::from(self);
+        for _ in 0..binds.len() {
+            if !local_nameless.has_free_in(&binds) {
+                break;
+            }
+            local_nameless.rebind(&mut binds);
+        }
+        ReducedTerm {
+            count: local_nameless.beta_reduce_limit(limit, reducer),
+            term: (&local_nameless).try_into().unwrap(),
+        }
+    }
+}
+
 impl<T: Clone> TryFrom<&LocalNamelessTerm<T>> for Term<T> {
     type Error = LocalNamelessError;
 
@@ -265,6 +926,6 @@ impl<T: Clone> TryFrom<&LocalNamelessTerm<T>> for Term<T> {
 
 impl<T> From<ReducedTerm<T>> for Term<T> {
     fn from(reduced: ReducedTerm<T>) -> Self {
-        reduced.term
+        reduced.into_term()
     }
 }
\ No newline at end of file
@@ -0,0 +1,62 @@
+//! Step budgets for β-reduction of [Term](crate::Term)s.
+
+/// A budget of β-reduction steps, which is used up as steps are performed.
+/// 
+/// [Fuel] is threaded through [BetaReduce::beta_reduce_fueled](crate::BetaReduce::beta_reduce_fueled), which all other default β-reduction loops of [BetaReduce](crate::BetaReduce) are built on.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// 
+/// let mut fuel = Fuel::new(1);
+/// assert!(fuel.consume());
+/// assert!(!fuel.consume());
+/// assert!(fuel.is_empty());
+/// 
+/// let mut fuel = Fuel::unlimited();
+/// assert!(fuel.consume());
+/// assert_eq!(fuel.remaining(), None);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Fuel {
+    remaining: Option<usize>,
+}
+
+impl Fuel {
+    /// Creates [Fuel] that allows at most the specified number of β-reduction steps.
+    pub fn new(steps: usize) -> Self {
+        Self {
+            remaining: Some(steps),
+        }
+    }
+
+    /// Creates [Fuel] that allows any number of β-reduction steps.
+    pub fn unlimited() -> Self {
+        Self {
+            remaining: None,
+        }
+    }
+
+    /// Returns the number of β-reduction steps still allowed, or [None] if the [Fuel] is unlimited.
+    pub fn remaining(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    /// Returns `true` if no more β-reduction steps are allowed.
+    pub fn is_empty(&self) -> bool {
+        self.remaining == Some(0)
+    }
+
+    /// Uses up one β-reduction step, and returns `false` if no more steps were allowed.
+    pub fn consume(&mut self) -> bool {
+        match &mut self.remaining {
+            Some(0) => false,
+            Some(remaining) => {
+                *remaining -= 1;
+                true
+            },
+            None => true,
+        }
+    }
+}
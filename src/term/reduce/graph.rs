@@ -0,0 +1,282 @@
+//! Exploration of the reduction graph of [Term]s.
+//! 
+//! Every β-redex in a [Term] can be contracted independently of the others, so a [Term] may have several different one-step reducts.
+//! Following these steps from a [Term] produces its reduction graph, which the functions in this module explore.
+
+use std::fmt::Display;
+use std::fmt::Write;
+
+use crate::BetaReduce;
+use crate::LocalNamelessTerm;
+use crate::Term;
+use crate::TraversalOrder;
+use crate::Var;
+
+/// Evidence of whether a [Term] is weakly or strongly normalizing, produced by [Term::probe_normalization].
+/// 
+/// Both fields are heuristic, as normalization is undecidable in general.
+/// A value of `false` only means that no evidence of normalization was found within the limit.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NormalizationProbe {
+    /// Whether the [Term] reached β-normal form within the limit, which is evidence that it is weakly normalizing.
+    pub weak: bool,
+    /// Whether every reduction path of the [Term] terminated within the limit, which is evidence that it is strongly normalizing.
+    pub strong: bool,
+}
+
+impl<T: Clone> LocalNamelessTerm<T> {
+    /// Returns every [LocalNamelessTerm] that can be reached by contracting exactly one β-redex.
+    /// 
+    /// The reducts are ordered by the position of the contracted redex, with outer redexes before inner ones and functions before arguments.
+    /// This is the same order in which the redexes are visited by a pre-order traversal (see [TraversalOrder::PreOrder]).
+    pub(crate) fn reducts(&self) -> Vec<Self> {
+        match self {
+            Self::Var(_) => Vec::new(),
+            Self::Abs(param, body) => body.reducts()
+                .into_iter()
+                .map(|body| Self::abs(param.clone(), body))
+                .collect(),
+            Self::App(func, arg) => {
+                let mut reducts = Vec::new();
+                if let Self::Abs(_, body) = func.as_ref() {
+                    let mut contracted = body.as_ref().clone();
+                    contracted.substitute_index(0, arg);
+                    reducts.push(contracted);
+                }
+                reducts.extend(func.reducts()
+                    .into_iter()
+                    .map(|func| Self::app(func, arg.as_ref().clone())));
+                reducts.extend(arg.reducts()
+                    .into_iter()
+                    .map(|arg| Self::app(func.as_ref().clone(), arg)));
+                reducts
+            },
+        }
+    }
+}
+
+impl<T: Clone + Eq> Term<T> {
+    /// Explores the [Term]'s reduction graph and returns every normal form reachable from it.
+    /// 
+    /// The graph is explored breadth-first, visiting at most `max_nodes` distinct [Term]s (up to α-equivalence), so [Term]s with large or infinite reduction graphs are only partially explored.
+    /// Normal forms are likewise only collected once per α-equivalence class.
+    /// 
+    /// By the Church-Rosser theorem, a [Term] has at most one normal form up to α-equivalence, so the resulting [Vec] should never contain more than one [Term].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!((λ x. x) ((λ y. y) z));
+    /// assert_eq!(term.normal_forms(16), vec![lambda!(z)]);
+    /// ```
+    pub fn normal_forms(&self, max_nodes: usize) -> Vec<Term<T>> {
+        ReductionGraph::explore(self, max_nodes).nodes
+            .iter()
+            .filter(|node| node.normal)
+            .map(|node| Term::try_from(&node.term).unwrap())
+            .collect()
+    }
+
+    /// Probes whether the [Term] is weakly or strongly normalizing.
+    /// 
+    /// Weak normalization is probed by β-reducing the [Term] up to `limit` steps using the specified [BetaReduce] `impl`ementation, and checking whether it reaches β-normal form.
+    /// Strong normalization is probed by exploring up to `limit` distinct [Term]s in the [Term]'s reduction graph (see [Term::normal_forms]), and checking that it is finite and contains no cycles.
+    /// 
+    /// This is intended as a teaching or research aid, and is a heuristic in both cases (see [NormalizationProbe]).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let omega = lambda!((λ x. x x) (λ x. x x));
+    /// let term = app!(lambda!(λ x. z), omega);
+    /// assert_eq!(term.probe_normalization(100, &Normal::default()), NormalizationProbe {
+    ///     weak: true,
+    ///     strong: false,
+    /// });
+    /// ```
+    pub fn probe_normalization<B: BetaReduce<Var<T>>>(&self, limit: usize, reducer: &B) -> NormalizationProbe {
+        let mut local_nameless = LocalNamelessTerm::from(self);
+        let count = local_nameless.beta_reduce_limit(limit, reducer);
+        let weak = count < limit || !local_nameless.beta_reduce_step(reducer);
+
+        let graph = ReductionGraph::explore(self, limit);
+        let strong = graph.complete && graph.is_acyclic();
+
+        NormalizationProbe {
+            weak,
+            strong,
+        }
+    }
+
+    /// Counts the distinct reduction paths from the [Term] to its β-normal form, by exploring up to `max_nodes` distinct [Term]s in its reduction graph (see [Term::normal_forms]).
+    /// 
+    /// Two paths are distinct if they contract a different β-redex at any step, even if the resulting [Term]s are α-equivalent.
+    /// A [Term] that is already in β-normal form has exactly one (empty) reduction path.
+    /// 
+    /// This returns [None] if the reduction graph has more than `max_nodes` distinct [Term]s, if it contains a cycle (in which case there are infinitely many paths), or if the number of paths does not fit in a [usize].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!((λ x. f x) ((λ y. y) z));
+    /// assert_eq!(term.count_reductions_all_paths_bounded(16), Some(2));
+    /// assert_eq!(term.count_reductions_all_paths_bounded(3), None);
+    /// 
+    /// assert_eq!(lambda!(λ x. x).count_reductions_all_paths_bounded(16), Some(1));
+    /// assert_eq!(lambda!((λ x. x x) (λ x. x x)).count_reductions_all_paths_bounded(16), None);
+    /// ```
+    pub fn count_reductions_all_paths_bounded(&self, max_nodes: usize) -> Option<usize> {
+        let graph = ReductionGraph::explore(self, max_nodes);
+        if !graph.complete || !graph.is_acyclic() {
+            return None;
+        }
+        graph.count_paths(0, &mut vec![None; graph.nodes.len()])
+    }
+}
+
+impl<T: Clone + Display + Eq> Term<T> {
+    /// Explores the [Term]'s reduction graph (see [Term::normal_forms]) and returns it in the DOT language, for visualization using Graphviz.
+    /// 
+    /// Every node is a distinct [Term] (up to α-equivalence) labelled with the [Term] itself, and every edge is a single β-reduction step labelled with the β-redex it contracts.
+    /// The node for the [Term] itself is always numbered `0`, and nodes for normal forms are drawn with a double border.
+    /// At most `max_nodes` nodes are visited, and steps leading to [Term]s beyond that are left out.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!((λ x. f x) ((λ y. y) z));
+    /// let dot = term.reduction_graph_dot(16);
+    /// assert!(dot.starts_with("digraph {"));
+    /// assert!(dot.contains(r#"0 [label="(λx. f x) ((λy. y) z)"];"#));
+    /// assert!(dot.contains(r#"[label="f z", peripheries=2];"#));
+    /// assert!(dot.contains(r#"0 -> 1 [label="(λx. f x) ((λy. y) z)"];"#));
+    /// 
+    /// let nodes = dot.lines().filter(|line| line.contains("[label=") && !line.contains("->")).count();
+    /// let edges = dot.lines().filter(|line| line.contains("->")).count();
+    /// assert_eq!((nodes, edges), (4, 4));
+    /// ```
+    pub fn reduction_graph_dot(&self, max_nodes: usize) -> String {
+        let graph = ReductionGraph::explore(self, max_nodes);
+        let terms = graph.nodes.iter()
+            .map(|node| Term::try_from(&node.term).unwrap())
+            .collect::<Vec<_>>();
+        let mut dot = String::from("digraph {\n");
+        for (index, (node, term)) in graph.nodes.iter().zip(&terms).enumerate() {
+            let border = if node.normal { ", peripheries=2" } else { "" };
+            writeln!(dot, "    {} [label={}{}];", index, dot_string(term), border).unwrap();
+        }
+        for (index, (node, term)) in graph.nodes.iter().zip(&terms).enumerate() {
+            let redexes = term.traverse(TraversalOrder::PreOrder)
+                .filter(|subterm| matches!(subterm, Self::App(func, _) if matches!(func.as_ref(), Self::Abs(_, _))))
+                .collect::<Vec<_>>();
+            for &(redex, reduct) in &node.reducts {
+                writeln!(dot, "    {} -> {} [label={}];", index, reduct, dot_string(redexes[redex])).unwrap();
+            }
+        }
+        dot.push('}');
+        dot
+    }
+}
+
+fn dot_string(term: &impl Display) -> String {
+    format!("\"{}\"", term.to_string().replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+struct ReductionGraph<T> {
+    nodes: Vec<ReductionNode<T>>,
+    complete: bool,
+}
+
+struct ReductionNode<T> {
+    term: LocalNamelessTerm<T>,
+    // Each reduct is paired with the position of the contracted redex in the order of LocalNamelessTerm::reducts
+    reducts: Vec<(usize, usize)>,
+    normal: bool,
+}
+
+impl<T: Clone + Eq> ReductionGraph<T> {
+    fn explore(term: &Term<T>, max_nodes: usize) -> Self {
+        let mut graph = Self {
+            nodes: vec![ReductionNode::new(LocalNamelessTerm::from(term))],
+            complete: true,
+        };
+        let mut next = 0;
+        while next < graph.nodes.len() {
+            let reducts = graph.nodes[next].term.reducts();
+            graph.nodes[next].normal = reducts.is_empty();
+            for (redex, reduct) in reducts.into_iter().enumerate() {
+                let index = match graph.nodes.iter().position(|node| node.term.nameless_eq(&reduct)) {
+                    Some(index) => index,
+                    None if graph.nodes.len() < max_nodes => {
+                        graph.nodes.push(ReductionNode::new(reduct));
+                        graph.nodes.len() - 1
+                    },
+                    None => {
+                        graph.complete = false;
+                        continue;
+                    },
+                };
+                graph.nodes[next].reducts.push((redex, index));
+            }
+            next += 1;
+        }
+        graph
+    }
+
+    // Only terminates if the graph is acyclic, and memoizes the number of paths from each node to a normal form
+    fn count_paths(&self, index: usize, counts: &mut Vec<Option<usize>>) -> Option<usize> {
+        if let Some(count) = counts[index] {
+            return Some(count);
+        }
+        let node = &self.nodes[index];
+        let count = if node.normal {
+            1
+        } else {
+            node.reducts
+                .iter()
+                .try_fold(0usize, |count, &(_, reduct)| count.checked_add(self.count_paths(reduct, counts)?))?
+        };
+        counts[index] = Some(count);
+        Some(count)
+    }
+
+    fn is_acyclic(&self) -> bool {
+        // Kahn's algorithm - the graph is acyclic if and only if every node can be removed in topological order
+        let mut in_degrees = vec![0; self.nodes.len()];
+        for &(_, reduct) in self.nodes.iter().flat_map(|node| &node.reducts) {
+            in_degrees[reduct] += 1;
+        }
+        let mut roots = (0..self.nodes.len())
+            .filter(|&index| in_degrees[index] == 0)
+            .collect::<Vec<_>>();
+        let mut removed = 0;
+        while let Some(index) = roots.pop() {
+            removed += 1;
+            for &(_, reduct) in &self.nodes[index].reducts {
+                in_degrees[reduct] -= 1;
+                if in_degrees[reduct] == 0 {
+                    roots.push(reduct);
+                }
+            }
+        }
+        removed == self.nodes.len()
+    }
+}
+
+impl<T> ReductionNode<T> {
+    fn new(term: LocalNamelessTerm<T>) -> Self {
+        Self {
+            term,
+            reducts: Vec::new(),
+            normal: false,
+        }
+    }
+}
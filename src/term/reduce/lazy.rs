@@ -0,0 +1,153 @@
+//! Lazy (call-by-need and call-by-name) evaluation of [Term]s.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::LocalNamelessTerm;
+use crate::ReducedTerm;
+use crate::Term;
+use crate::Var;
+
+impl<T: Clone + Eq> Term<T> {
+    /// Returns a fully β-reduced version of the [Term] wrapped in a [ReducedTerm] using call-by-need evaluation.
+    /// 
+    /// Arguments are not β-reduced until they are actually used, and are then shared by every use, so each argument is β-reduced at most once.
+    /// The [ReducedTerm]'s count is the number of β-reductions performed, which may be lower than that of [Term::eval_call_by_name] when arguments are used more than once.
+    /// 
+    /// Like [Term::beta_reduced], this does not terminate if the [Term] has no β-normal form.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!((λ x. f x x) ((λ y. y) a));
+    /// 
+    /// let need = term.eval_call_by_need();
+    /// let name = term.eval_call_by_name();
+    /// assert_eq!(need.term, lambda!(f a a));
+    /// assert_eq!(name.term, lambda!(f a a));
+    /// assert_eq!(need.count, 2);
+    /// assert_eq!(name.count, 3);
+    /// ```
+    pub fn eval_call_by_need(&self) -> ReducedTerm<T> {
+        Eval {
+            share: true,
+            count: 0,
+        }.eval_term(self)
+    }
+
+    /// Returns a fully β-reduced version of the [Term] wrapped in a [ReducedTerm] using call-by-name evaluation.
+    /// 
+    /// Arguments are not β-reduced until they are actually used, but unlike [Term::eval_call_by_need], they are β-reduced again every time they are used.
+    /// The [ReducedTerm]'s count is the number of β-reductions performed, including repeated ones.
+    /// 
+    /// Like [Term::beta_reduced], this does not terminate if the [Term] has no β-normal form.
+    pub fn eval_call_by_name(&self) -> ReducedTerm<T> {
+        Eval {
+            share: false,
+            count: 0,
+        }.eval_term(self)
+    }
+}
+
+struct Eval {
+    share: bool,
+    count: usize,
+}
+
+impl Eval {
+    fn eval_term<T: Clone + Eq>(mut self, term: &Term<T>) -> ReducedTerm<T> {
+        let local_nameless = LocalNamelessTerm::from(term);
+        let value = self.eval(&local_nameless, &Vec::new());
+        let term = self.quote(value, 0);
+        ReducedTerm {
+            count: self.count,
+            term: (&term).try_into().unwrap(),
+        }
+    }
+
+    fn eval<'t, T: Clone>(&mut self, term: &'t LocalNamelessTerm<T>, env: &Env<'t, T>) -> Value<'t, T> {
+        match term {
+            Term::Var(Var::Bound(index)) => self.force(&env[env.len() - 1 - index]),
+            Term::Var(Var::Free(var)) => Value::Neutral(Head::Free(var), Vec::new()),
+            Term::Abs(param, body) => Value::Closure(param, body, env.clone()),
+            Term::App(func, arg) => {
+                let func = self.eval(func, env);
+                let arg = match arg.as_ref() {
+                    // Variables are passed along as-is, so that every use of an argument shares the same thunk
+                    Term::Var(Var::Bound(index)) => env[env.len() - 1 - index].clone(),
+                    arg => Rc::new(RefCell::new(Thunk::Delayed(arg, env.clone()))),
+                };
+                self.apply(func, arg)
+            },
+        }
+    }
+
+    fn apply<'t, T: Clone>(&mut self, func: Value<'t, T>, arg: Shared<'t, T>) -> Value<'t, T> {
+        match func {
+            Value::Closure(_, body, mut env) => {
+                self.count += 1;
+                env.push(arg);
+                self.eval(body, &env)
+            },
+            Value::Neutral(head, mut args) => {
+                args.push(arg);
+                Value::Neutral(head, args)
+            },
+        }
+    }
+
+    fn force<'t, T: Clone>(&mut self, thunk: &Shared<'t, T>) -> Value<'t, T> {
+        let (term, env) = match &*thunk.borrow() {
+            Thunk::Forced(value) => return value.clone(),
+            Thunk::Delayed(term, env) => (*term, env.clone()),
+        };
+        let value = self.eval(term, &env);
+        if self.share {
+            *thunk.borrow_mut() = Thunk::Forced(value.clone());
+        }
+        value
+    }
+
+    fn quote<T: Clone>(&mut self, value: Value<'_, T>, depth: usize) -> LocalNamelessTerm<T> {
+        match value {
+            Value::Closure(param, body, mut env) => {
+                env.push(Rc::new(RefCell::new(Thunk::Forced(Value::Neutral(Head::Level(depth), Vec::new())))));
+                let body = self.eval(body, &env);
+                LocalNamelessTerm::abs(param.clone(), self.quote(body, depth + 1))
+            },
+            Value::Neutral(head, args) => {
+                let head = match head {
+                    Head::Free(var) => LocalNamelessTerm::var(Var::Free(var.clone())),
+                    Head::Level(level) => LocalNamelessTerm::var(Var::Bound(depth - 1 - level)),
+                };
+                args.iter().fold(head, |func, arg| {
+                    let arg = self.force(arg);
+                    LocalNamelessTerm::app(func, self.quote(arg, depth))
+                })
+            },
+        }
+    }
+}
+
+type Env<'t, T> = Vec<Shared<'t, T>>;
+
+type Shared<'t, T> = Rc<RefCell<Thunk<'t, T>>>;
+
+enum Thunk<'t, T> {
+    Delayed(&'t LocalNamelessTerm<T>, Env<'t, T>),
+    Forced(Value<'t, T>),
+}
+
+#[derive(Clone)]
+enum Value<'t, T> {
+    Closure(&'t Var<T>, &'t LocalNamelessTerm<T>, Env<'t, T>),
+    Neutral(Head<'t, T>, Vec<Shared<'t, T>>),
+}
+
+#[derive(Clone, Copy)]
+enum Head<'t, T> {
+    Free(&'t T),
+    Level(usize),
+}
@@ -0,0 +1,63 @@
+//! Heuristic diagnosis of why β-reduction of a [Term] does not terminate.
+
+use std::collections::HashMap;
+
+use crate::BetaReduce;
+use crate::LocalNamelessTerm;
+use crate::Term;
+use crate::Var;
+
+/// A likely reason for a [Term] not reaching β-normal form, as diagnosed by [Term::diagnose_nontermination].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NonTermReason {
+    /// The [Term] β-reduced to a [Term] it had already β-reduced to before (up to α-equivalence), so β-reduction loops forever.
+    Cyclic,
+    /// The size of the [Term] increased with every β-reduction step in the second half of the steps performed, so β-reduction is likely to keep expanding it forever.
+    Growing,
+    /// Neither of the other reasons applies, so the [Term] may or may not reach β-normal form given more steps.
+    Unknown,
+}
+
+impl<T: Clone + Eq> Term<T> {
+    /// Performs up to `limit` β-reduction steps on a copy of the [Term] using the specified [BetaReduce] `impl`ementation, and returns a likely reason for it not reaching β-normal form, or [None] if it does reach β-normal form within the limit.
+    /// 
+    /// Only [NonTermReason::Cyclic] is conclusive - since the strategy is deterministic, a recurring [Term] proves that β-reduction never terminates.
+    /// [NonTermReason::Growing] and [NonTermReason::Unknown] are only hints, and a larger limit may lead to a different diagnosis.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let omega = lambda!((λ x. x x) (λ x. x x));
+    /// assert_eq!(omega.diagnose_nontermination(&Normal::default(), 10), Some(NonTermReason::Cyclic));
+    /// 
+    /// let growing = lambda!((λ x. x x x) (λ x. x x x));
+    /// assert_eq!(growing.diagnose_nontermination(&Normal::default(), 10), Some(NonTermReason::Growing));
+    /// 
+    /// assert_eq!(lambda!((λ x. x) y).diagnose_nontermination(&Normal::default(), 10), None);
+    /// assert_eq!(growing.diagnose_nontermination(&Normal::default(), 0), Some(NonTermReason::Unknown));
+    /// ```
+    pub fn diagnose_nontermination<B: BetaReduce<Var<T>>>(&self, reducer: &B, limit: usize) -> Option<NonTermReason> {
+        let mut term = LocalNamelessTerm::from(self);
+        // Previously seen terms are grouped by size, since only terms of the same size can be α-equivalent
+        let mut seen = HashMap::<usize, Vec<LocalNamelessTerm<T>>>::new();
+        let mut sizes = Vec::with_capacity(limit + 1);
+        // One step beyond the limit is attempted, since the β-normal form may be reached in exactly as many steps as the limit
+        for _ in 0..=limit {
+            let size = term.size();
+            let same_size = seen.entry(size).or_default();
+            if same_size.iter().any(|other| other.nameless_eq(&term)) {
+                return Some(NonTermReason::Cyclic);
+            }
+            same_size.push(term.clone());
+            sizes.push(size);
+            if !reducer.beta_reduce_step(&mut term) {
+                return None;
+            }
+        }
+        let second_half = &sizes[sizes.len() / 2..];
+        let growing = second_half.len() > 1 && second_half.windows(2).all(|pair| pair[0] < pair[1]);
+        Some(if growing { NonTermReason::Growing } else { NonTermReason::Unknown })
+    }
+}
@@ -1,35 +1,90 @@
 //! The normal-order β-reduction strategy.
 
-use std::mem;
-
 use crate::BetaReduce;
+use crate::Direction;
 use crate::LocalNamelessTerm;
+use crate::ReduceStats;
+use crate::StepTrace;
 use crate::Var;
 
 /// The normal-order β-reduction strategy for [Term](crate::Term)s.
 /// 
 /// This strategy reduces the leftmost, outermost β-redexes first.
-pub struct Normal;
+/// 
+/// By default, β-redexes inside the bodies of abstractions are reduced as well (see [Normal::full]).
+/// A [Normal::weak] strategy leaves abstraction bodies untouched instead, stopping once no β-redexes remain outside of them.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// 
+/// let term = lambda!(λ x. (λ y. y) z);
+/// assert_eq!(term.beta_reduced(&Normal::weak()).term, lambda!(λ x. (λ y. y) z));
+/// assert_eq!(term.beta_reduced(&Normal::full()).term, lambda!(λ x. z));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Normal {
+    /// Whether β-redexes inside the bodies of abstractions are reduced.
+    pub under_binders: bool,
+}
+
+impl Normal {
+    /// Creates a normal-order strategy that does not reduce inside the bodies of abstractions.
+    pub fn weak() -> Self {
+        Self {
+            under_binders: false,
+        }
+    }
+
+    /// Creates a normal-order strategy that also reduces inside the bodies of abstractions.
+    pub fn full() -> Self {
+        Self {
+            under_binders: true,
+        }
+    }
+}
+
+impl Default for Normal {
+    fn default() -> Self {
+        Self::full()
+    }
+}
 
 impl<T: Clone> BetaReduce<Var<T>> for Normal {
     fn beta_reduce_step(&self, term: &mut LocalNamelessTerm<T>) -> bool {
+        self.beta_reduce_step_traced(term, &mut StepTrace::default())
+    }
+
+    fn beta_reduce_step_located(&self, term: &mut LocalNamelessTerm<T>) -> Option<Vec<Direction>> {
+        let mut trace = StepTrace::default();
+        self.beta_reduce_step_traced(term, &mut trace);
+        trace.into_location()
+    }
+
+    fn beta_reduce_step_with_stats(&self, term: &mut LocalNamelessTerm<T>, stats: &mut ReduceStats) -> bool {
+        self.beta_reduce_step_traced(term, &mut StepTrace::with_stats(stats))
+    }
+}
+
+impl Normal {
+    fn beta_reduce_step_traced<T: Clone>(&self, term: &mut LocalNamelessTerm<T>, trace: &mut StepTrace<'_>) -> bool {
         match term {
             LocalNamelessTerm::Var(_) => false,
-            LocalNamelessTerm::Abs(_, body) => self.beta_reduce_step(body),
+            LocalNamelessTerm::Abs(_, body) => self.under_binders && trace.descend(Direction::Body, |trace| self.beta_reduce_step_traced(body, trace)),
             LocalNamelessTerm::App(func, arg) => match func.as_mut() {
                 LocalNamelessTerm::Abs(_, body) => {
-                    self.beta_reduce_step(body);
-                    body.open(0, arg);
-                    // The body is replaced with a dummy value but the entire abstraction ceases to exist and cannot be accessed again, so this is ok
-                    *term = mem::replace(body, LocalNamelessTerm::var(Var::Bound(0)));
-                    true
+                    if self.under_binders {
+                        trace.descend(Direction::Func, |trace| trace.descend(Direction::Body, |trace| self.beta_reduce_step_traced(body, trace)));
+                    }
+                    trace.contract(term)
                 },
                 func => {
-                    let func_reduced = self.beta_reduce_step(func);
-                    let arg_reduced = self.beta_reduce_step(arg);
+                    let func_reduced = trace.descend(Direction::Func, |trace| self.beta_reduce_step_traced(func, trace));
+                    let arg_reduced = trace.descend(Direction::Arg, |trace| self.beta_reduce_step_traced(arg, trace));
                     func_reduced || arg_reduced
                 },
             },
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,125 @@
+//! The parallel β-reduction strategy.
+
+use crate::BetaReduce;
+use crate::Direction;
+use crate::LocalNamelessTerm;
+use crate::Normal;
+use crate::ReduceStats;
+use crate::StepTrace;
+use crate::Term;
+use crate::Var;
+
+/// The parallel β-reduction strategy for [Term](crate::Term)s.
+/// 
+/// This strategy contracts every β-redex in the [Term](crate::Term) simultaneously in each step (a complete development), including those inside the bodies of abstractions.
+/// β-redexes created by a step are only contracted in the next step, while copies of existing β-redexes made by a step are contracted in that same step.
+/// 
+/// Since every step contracts at least the leftmost, outermost β-redex, this strategy reaches the β-normal form of every [Term](crate::Term) that has one, usually in far fewer steps than [Normal](crate::Normal).
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// 
+/// let mut term = LocalNamelessTerm::from(&lambda!(f ((λ x. x) a) ((λ y. (λ z. z) y) b)));
+/// 
+/// assert!(term.beta_reduce_step(&Parallel));
+/// assert_eq!(Term::try_from(&term).unwrap(), lambda!(f a b));
+/// 
+/// assert!(!term.beta_reduce_step(&Parallel));
+/// ```
+pub struct Parallel;
+
+impl<T: Clone> BetaReduce<Var<T>> for Parallel {
+    fn beta_reduce_step(&self, term: &mut LocalNamelessTerm<T>) -> bool {
+        self.beta_reduce_step_traced(term, &mut StepTrace::default())
+    }
+
+    fn beta_reduce_step_located(&self, term: &mut LocalNamelessTerm<T>) -> Option<Vec<Direction>> {
+        let mut trace = StepTrace::default();
+        self.beta_reduce_step_traced(term, &mut trace);
+        trace.into_location()
+    }
+
+    fn beta_reduce_step_with_stats(&self, term: &mut LocalNamelessTerm<T>, stats: &mut ReduceStats) -> bool {
+        self.beta_reduce_step_traced(term, &mut StepTrace::with_stats(stats))
+    }
+}
+
+impl Parallel {
+    fn beta_reduce_step_traced<T: Clone>(&self, term: &mut LocalNamelessTerm<T>, trace: &mut StepTrace<'_>) -> bool {
+        match term {
+            LocalNamelessTerm::Var(_) => false,
+            LocalNamelessTerm::Abs(_, body) => trace.descend(Direction::Body, |trace| self.beta_reduce_step_traced(body, trace)),
+            LocalNamelessTerm::App(func, arg) => {
+                // Only β-redexes that existed before the step are contracted, even if reducing the function turns it into an abstraction
+                let is_redex = matches!(func.as_ref(), LocalNamelessTerm::Abs(_, _));
+                // Both sides are reduced before substituting, so every copy of the argument is already reduced
+                let func_reduced = trace.descend(Direction::Func, |trace| self.beta_reduce_step_traced(func, trace));
+                let arg_reduced = trace.descend(Direction::Arg, |trace| self.beta_reduce_step_traced(arg, trace));
+                (is_redex && trace.contract(term)) || func_reduced || arg_reduced
+            },
+        }
+    }
+}
+
+impl<T: Clone + Eq> Term<T> {
+    /// Returns the number of [Parallel] β-reduction steps needed for the [Term] to reach β-normal form, or [None] if it does not reach β-normal form within the limit.
+    /// 
+    /// This is usually less than the number of steps needed by strategies that contract one β-redex at a time, since independent β-redexes are contracted together.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!((λ x. x) ((λ y. y) z));
+    /// assert_eq!(term.simultaneous_parallel_step_count(10), Some(1));
+    /// assert_eq!(term.beta_reduced(&Normal::default()).count, 2);
+    /// 
+    /// // The β-redex with λy. y is only created by the first step
+    /// assert_eq!(lambda!((λ x. x) (λ y. y) a).simultaneous_parallel_step_count(10), Some(2));
+    /// 
+    /// assert_eq!(lambda!(λ x. x).simultaneous_parallel_step_count(10), Some(0));
+    /// assert_eq!(lambda!((λ x. x x) (λ x. x x)).simultaneous_parallel_step_count(10), None);
+    /// ```
+    pub fn simultaneous_parallel_step_count(&self, limit: usize) -> Option<usize> {
+        let mut local_nameless = LocalNamelessTerm::from(self);
+        // One step beyond the limit is attempted, since the β-normal form may be reached in exactly as many steps as the limit
+        let count = local_nameless.beta_reduce_while(|_, count| count <= limit, &Parallel);
+        (count <= limit).then_some(count)
+    }
+
+    /// Returns the β-normal form of the [Term] by applying [Parallel] β-reduction steps until it stops changing, or [None] if it does not reach β-normal form within the limit.
+    /// 
+    /// In debug builds, the result is cross-checked against the β-normal form reached by [Normal] β-reduction within the same limit (if any), and a mismatch triggers a panic.
+    /// By the Church-Rosser theorem, both must be α-equivalent, so a mismatch indicates a bug in one of the strategies.
+    /// In release builds, only [Parallel] β-reduction is performed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!((λ f x. f (f x)) (λ y. (λ z. z) y) w);
+    /// assert_eq!(term.normalize_parallel_until_fixpoint(10), Some(lambda!(w)));
+    /// 
+    /// assert_eq!(lambda!((λ x. x x) (λ x. x x)).normalize_parallel_until_fixpoint(10), None);
+    /// ```
+    pub fn normalize_parallel_until_fixpoint(&self, limit: usize) -> Option<Term<T>> {
+        let mut local_nameless = LocalNamelessTerm::from(self);
+        let count = local_nameless.beta_reduce_while(|_, count| count <= limit, &Parallel);
+        if count > limit {
+            return None;
+        }
+        if cfg!(debug_assertions) {
+            let mut normal = LocalNamelessTerm::from(self);
+            let normal_count = normal.beta_reduce_while(|_, count| count <= limit, &Normal::default());
+            debug_assert!(
+                normal_count > limit || normal.nameless_eq(&local_nameless),
+                "parallel and normal-order β-reduction reached different β-normal forms",
+            );
+        }
+        Some((&local_nameless).try_into().unwrap())
+    }
+}
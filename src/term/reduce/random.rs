@@ -0,0 +1,113 @@
+//! The randomised β-reduction strategy.
+
+use std::cell::Cell;
+
+use crate::BetaReduce;
+use crate::Direction;
+use crate::LocalNamelessTerm;
+use crate::ReduceStats;
+use crate::ReducedTerm;
+use crate::StepTrace;
+use crate::Term;
+use crate::Var;
+
+/// A randomised β-reduction strategy for [Term]s.
+/// 
+/// This strategy contracts exactly one β-redex per step, chosen uniformly at random among all β-redexes in the [Term] (including nested ones).
+/// The choices are made by a small pseudo-random number generator seeded on creation, so two instances created with the same seed always make the same choices on the same [Term]s.
+/// 
+/// Since the untyped lambda calculus is confluent, any β-normal form reached by this strategy is the same as the one reached by [Normal](crate::Normal).
+/// This makes it useful for testing other strategies, although it may fail to terminate on [Term]s that have a β-normal form, such as `(λx. y) ((λx. x x) (λx. x x))`.
+pub struct RandomStrategy {
+    state: Cell<u64>,
+}
+
+impl RandomStrategy {
+    /// Creates a randomised strategy whose choices are determined by the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: Cell::new(seed),
+        }
+    }
+
+    fn beta_reduce_step_traced<T: Clone>(&self, term: &mut LocalNamelessTerm<T>, trace: &mut StepTrace<'_>) -> bool {
+        match count_redexes(term) {
+            0 => false,
+            count => contract_nth_redex(term, &mut ((self.next() % count as u64) as usize), trace),
+        }
+    }
+
+    // SplitMix64, which is more than good enough for picking β-redexes
+    fn next(&self) -> u64 {
+        let state = self.state.get().wrapping_add(0x9E3779B97F4A7C15);
+        self.state.set(state);
+        let mixed = (state ^ (state >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        let mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94D049BB133111EB);
+        mixed ^ (mixed >> 31)
+    }
+}
+
+impl<T: Clone> BetaReduce<Var<T>> for RandomStrategy {
+    fn beta_reduce_step(&self, term: &mut LocalNamelessTerm<T>) -> bool {
+        self.beta_reduce_step_traced(term, &mut StepTrace::default())
+    }
+
+    fn beta_reduce_step_located(&self, term: &mut LocalNamelessTerm<T>) -> Option<Vec<Direction>> {
+        let mut trace = StepTrace::default();
+        self.beta_reduce_step_traced(term, &mut trace);
+        trace.into_location()
+    }
+
+    fn beta_reduce_step_with_stats(&self, term: &mut LocalNamelessTerm<T>, stats: &mut ReduceStats) -> bool {
+        self.beta_reduce_step_traced(term, &mut StepTrace::with_stats(stats))
+    }
+}
+
+impl<T: Clone + Eq> Term<T> {
+    /// Returns a version of the [Term] β-reduced up to a certain limit using a [RandomStrategy] with the given seed, wrapped in a [ReducedTerm].
+    /// 
+    /// Reducing the same [Term] with the same seed always performs the same β-reductions.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!((λ x. f x x) ((λ y. y) ((λ z. z) a)));
+    /// let normal = term.beta_reduced(&Normal::default());
+    /// for seed in 0..16 {
+    ///     let random = term.beta_reduced_random(seed, 100);
+    ///     assert_eq!(random.term, normal.term);
+    ///     assert_eq!(term.beta_reduced_random(seed, 1).term, term.beta_reduced_random(seed, 1).term);
+    /// }
+    /// ```
+    pub fn beta_reduced_random(&self, seed: u64, limit: usize) -> ReducedTerm<T> {
+        self.beta_reduced_limit(limit, &RandomStrategy::new(seed))
+    }
+}
+
+fn count_redexes<T>(term: &LocalNamelessTerm<T>) -> usize {
+    match term {
+        LocalNamelessTerm::Var(_) => 0,
+        LocalNamelessTerm::Abs(_, body) => count_redexes(body),
+        LocalNamelessTerm::App(func, arg) => {
+            let redex = matches!(func.as_ref(), LocalNamelessTerm::Abs(_, _)) as usize;
+            redex + count_redexes(func) + count_redexes(arg)
+        },
+    }
+}
+
+fn contract_nth_redex<T: Clone>(term: &mut LocalNamelessTerm<T>, n: &mut usize, trace: &mut StepTrace<'_>) -> bool {
+    match term {
+        LocalNamelessTerm::Var(_) => false,
+        LocalNamelessTerm::Abs(_, body) => trace.descend(Direction::Body, |trace| contract_nth_redex(body, n, trace)),
+        LocalNamelessTerm::App(func, _) if *n == 0 && matches!(func.as_ref(), LocalNamelessTerm::Abs(_, _)) => trace.contract(term),
+        LocalNamelessTerm::App(func, arg) => {
+            if matches!(func.as_ref(), LocalNamelessTerm::Abs(_, _)) {
+                *n -= 1;
+            }
+            trace.descend(Direction::Func, |trace| contract_nth_redex(func, n, trace))
+                || trace.descend(Direction::Arg, |trace| contract_nth_redex(arg, n, trace))
+        },
+    }
+}
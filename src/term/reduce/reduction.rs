@@ -0,0 +1,78 @@
+//! Step-by-step β-reduction of [Term]s that can be suspended between steps.
+
+use crate::BetaReduce;
+use crate::LocalNamelessTerm;
+use crate::ReducedTerm;
+use crate::Term;
+use crate::Var;
+
+/// An in-progress β-reduction of a [Term], which performs one step at a time when driven by [Reduction::step].
+/// 
+/// Unlike [Term::reduction_frames], the [Term] is never cloned or converted between steps - the live [LocalNamelessTerm] is exposed by reference instead.
+/// This makes it possible to suspend β-reduction between steps (such as to yield to an async runtime) and resume it later without any overhead.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// 
+/// let mut reduction = Reduction::new(&lambda!((λ x. x) ((λ y. y) z)), Normal::default());
+/// assert_eq!(reduction.step(), Some(&LocalNamelessTerm::from(&lambda!((λ y. y) z))));
+/// assert_eq!(reduction.count(), 1);
+/// 
+/// assert_eq!(reduction.step(), Some(&LocalNamelessTerm::from(&lambda!(z))));
+/// assert_eq!(reduction.step(), None);
+/// assert_eq!(reduction.count(), 2);
+/// 
+/// assert_eq!(reduction.into_reduced().into_parts(), (2, lambda!(z)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Reduction<T, B> {
+    term: LocalNamelessTerm<T>,
+    reducer: B,
+    count: usize,
+}
+
+impl<T: Clone + Eq, B> Reduction<T, B> {
+    /// Creates a new [Reduction] of a [Term] using the specified [BetaReduce] `impl`ementation, without performing any steps yet.
+    pub fn new(term: &Term<T>, reducer: B) -> Self {
+        Self {
+            term: LocalNamelessTerm::from(term),
+            reducer,
+            count: 0,
+        }
+    }
+}
+
+impl<T: Clone, B: BetaReduce<Var<T>>> Reduction<T, B> {
+    /// Performs one step of β-reduction, and returns the [LocalNamelessTerm] after it, or [None] if it was already in β-normal form.
+    pub fn step(&mut self) -> Option<&LocalNamelessTerm<T>> {
+        if !self.reducer.beta_reduce_step(&mut self.term) {
+            return None;
+        }
+        self.count += 1;
+        Some(&self.term)
+    }
+}
+
+impl<T, B> Reduction<T, B> {
+    /// Returns the [LocalNamelessTerm] in its current state, after all steps performed so far.
+    pub fn term(&self) -> &LocalNamelessTerm<T> {
+        &self.term
+    }
+
+    /// Returns the number of β-reduction steps performed so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<T: Clone, B> Reduction<T, B> {
+    /// Consumes the [Reduction], returning the [Term] in its current state wrapped in a [ReducedTerm].
+    pub fn into_reduced(self) -> ReducedTerm<T> {
+        ReducedTerm {
+            count: self.count,
+            term: (&self.term).try_into().unwrap(),
+        }
+    }
+}
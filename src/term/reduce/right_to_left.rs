@@ -0,0 +1,71 @@
+//! The right-to-left applicative-order β-reduction strategy.
+
+use crate::BetaReduce;
+use crate::Direction;
+use crate::LocalNamelessTerm;
+use crate::ReduceStats;
+use crate::StepTrace;
+use crate::Var;
+
+/// The right-to-left applicative-order β-reduction strategy for [Term](crate::Term)s.
+/// 
+/// This strategy reduces the rightmost, innermost β-redexes first, performing exactly one β-reduction per step.
+/// In particular, the argument of an application is always fully β-reduced before its function, and both are fully β-reduced before the application itself.
+/// 
+/// Unlike [Normal](crate::Normal), this strategy may fail to terminate on [Term]s that have a β-normal form, such as `(λx. y) ((λx. x x) (λx. x x))`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// 
+/// let mut term = LocalNamelessTerm::from(&lambda!(f ((λ x. x) a) ((λ y. y) b)));
+/// 
+/// assert!(term.beta_reduce_step(&RightToLeft));
+/// assert_eq!(Term::try_from(&term).unwrap(), lambda!(f ((λ x. x) a) b));
+/// 
+/// assert!(term.beta_reduce_step(&RightToLeft));
+/// assert_eq!(Term::try_from(&term).unwrap(), lambda!(f a b));
+/// 
+/// assert!(!term.beta_reduce_step(&RightToLeft));
+/// ```
+/// 
+/// This strategy also reports the exact location of each β-redex it contracts.
+/// 
+/// ```
+/// use lamb::*;
+/// 
+/// let mut term = LocalNamelessTerm::from(&lambda!((λ x. f x) f));
+/// assert_eq!(RightToLeft.beta_reduce_step_located(&mut term), Some(vec![]));
+/// ```
+pub struct RightToLeft;
+
+impl<T: Clone> BetaReduce<Var<T>> for RightToLeft {
+    fn beta_reduce_step(&self, term: &mut LocalNamelessTerm<T>) -> bool {
+        self.beta_reduce_step_traced(term, &mut StepTrace::default())
+    }
+
+    fn beta_reduce_step_located(&self, term: &mut LocalNamelessTerm<T>) -> Option<Vec<Direction>> {
+        let mut trace = StepTrace::default();
+        self.beta_reduce_step_traced(term, &mut trace);
+        trace.into_location()
+    }
+
+    fn beta_reduce_step_with_stats(&self, term: &mut LocalNamelessTerm<T>, stats: &mut ReduceStats) -> bool {
+        self.beta_reduce_step_traced(term, &mut StepTrace::with_stats(stats))
+    }
+}
+
+impl RightToLeft {
+    fn beta_reduce_step_traced<T: Clone>(&self, term: &mut LocalNamelessTerm<T>, trace: &mut StepTrace<'_>) -> bool {
+        match term {
+            LocalNamelessTerm::Var(_) => false,
+            LocalNamelessTerm::Abs(_, body) => trace.descend(Direction::Body, |trace| self.beta_reduce_step_traced(body, trace)),
+            LocalNamelessTerm::App(func, arg) => {
+                trace.descend(Direction::Arg, |trace| self.beta_reduce_step_traced(arg, trace))
+                    || trace.descend(Direction::Func, |trace| self.beta_reduce_step_traced(func, trace))
+                    || trace.contract(term)
+            },
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! The spine-strict β-reduction strategy, as used by lazy languages.
+
+use crate::BetaReduce;
+use crate::Direction;
+use crate::LocalNamelessTerm;
+use crate::ReduceStats;
+use crate::StepTrace;
+use crate::Var;
+use crate::Whnf;
+
+/// A spine-strict β-reduction strategy for [Term](crate::Term)s, resembling how lazy functional languages evaluate expressions.
+/// 
+/// This strategy first reduces the [Term](crate::Term) to weak head normal form (see [Whnf]), by repeatedly contracting the β-redex at the head of its application spine.
+/// Once the head is a variable, each application along the spine is passed to a strictness predicate, and the arguments of those for which it returns `true` are β-reduced using the same strategy (from left to right).
+/// All other arguments, as well as the bodies of abstractions, are left untouched.
+/// 
+/// Since the untyped lambda calculus carries no strictness information, the predicate decides which arguments are in strict positions.
+/// A predicate that always returns `false` reduces [Term](crate::Term)s to weak head normal form only.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// 
+/// // Only the first argument of f is strict
+/// let strategy = SpineStrict::new(|app: &LocalNamelessTerm<&str>| matches!(app, Term::App(func, _) if func.as_ref() == &Term::var(Var::Free("f"))));
+/// 
+/// let term = lambda!((λ g. g ((λ x. x) a) ((λ y. y) b)) f);
+/// assert_eq!(term.beta_reduced(&strategy).term, lambda!(f a ((λ y. y) b)));
+/// ```
+pub struct SpineStrict<F> {
+    is_strict: F,
+}
+
+impl<F> SpineStrict<F> {
+    /// Creates a spine-strict strategy that β-reduces the arguments of applications for which the predicate returns `true`.
+    pub fn new(is_strict: F) -> Self {
+        Self {
+            is_strict,
+        }
+    }
+
+    fn beta_reduce_step_traced<T: Clone>(&self, term: &mut LocalNamelessTerm<T>, trace: &mut StepTrace<'_>) -> bool
+    where
+        F: Fn(&LocalNamelessTerm<T>) -> bool, {
+            Whnf.beta_reduce_step_traced(term, trace) || self.strict_step(term, trace)
+        }
+
+    fn strict_step<T: Clone>(&self, term: &mut LocalNamelessTerm<T>, trace: &mut StepTrace<'_>) -> bool
+    where
+        F: Fn(&LocalNamelessTerm<T>) -> bool, {
+            let strict = matches!(term, LocalNamelessTerm::App(_, _)) && (self.is_strict)(term);
+            match term {
+                // Arguments further down the spine come first, so that they are forced from left to right
+                LocalNamelessTerm::App(func, arg) => {
+                    trace.descend(Direction::Func, |trace| self.strict_step(func, trace))
+                        || (strict && trace.descend(Direction::Arg, |trace| self.beta_reduce_step_traced(arg, trace)))
+                },
+                _ => false,
+            }
+        }
+}
+
+impl<T: Clone, F: Fn(&LocalNamelessTerm<T>) -> bool> BetaReduce<Var<T>> for SpineStrict<F> {
+    fn beta_reduce_step(&self, term: &mut LocalNamelessTerm<T>) -> bool {
+        self.beta_reduce_step_traced(term, &mut StepTrace::default())
+    }
+
+    fn beta_reduce_step_located(&self, term: &mut LocalNamelessTerm<T>) -> Option<Vec<Direction>> {
+        let mut trace = StepTrace::default();
+        self.beta_reduce_step_traced(term, &mut trace);
+        trace.into_location()
+    }
+
+    fn beta_reduce_step_with_stats(&self, term: &mut LocalNamelessTerm<T>, stats: &mut ReduceStats) -> bool {
+        self.beta_reduce_step_traced(term, &mut StepTrace::with_stats(stats))
+    }
+}
@@ -0,0 +1,12 @@
+//! Statistics collected while β-reducing [Term](crate::Term)s.
+
+/// Statistics about the work done while β-reducing a [Term](crate::Term) (see [BetaReduce::beta_reduce_with_stats](crate::BetaReduce::beta_reduce_with_stats)).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ReduceStats {
+    /// The number of bound variables that were replaced with arguments.
+    pub substitutions: usize,
+    /// The total number of nodes (variables, abstractions, and applications) in the copies of arguments that replaced bound variables.
+    pub nodes_copied: usize,
+    /// The largest number of nodes the [Term](crate::Term) had at any point, including before β-reduction.
+    pub max_size: usize,
+}
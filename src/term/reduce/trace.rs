@@ -0,0 +1,83 @@
+//! Tracking of where β-redexes are contracted during a β-reduction step.
+
+use std::mem;
+
+use crate::Direction;
+use crate::LocalNamelessTerm;
+use crate::ReduceStats;
+use crate::Var;
+
+/// Tracks the current position within a [LocalNamelessTerm] while a strategy searches it for β-redexes, and records where β-redexes are contracted.
+/// 
+/// Built-in strategies contract every β-redex through [StepTrace::contract], so that they can report exact locations (see [BetaReduce::beta_reduce_step_located](crate::BetaReduce::beta_reduce_step_located)) without comparing the [LocalNamelessTerm] before and after each step.
+/// The same applies to the substitutions and copied nodes counted in [ReduceStats] (see [BetaReduce::beta_reduce_step_with_stats](crate::BetaReduce::beta_reduce_step_with_stats)).
+#[derive(Debug, Default)]
+pub(crate) struct StepTrace<'s> {
+    path: Vec<Direction>,
+    location: Option<Vec<Direction>>,
+    stats: Option<&'s mut ReduceStats>,
+}
+
+impl<'s> StepTrace<'s> {
+    /// Creates a [StepTrace] that also adds the substitutions and copied nodes of every contracted β-redex to the [ReduceStats].
+    pub(crate) fn with_stats(stats: &'s mut ReduceStats) -> Self {
+        Self {
+            stats: Some(stats),
+            ..Self::default()
+        }
+    }
+
+    /// Runs a search step on an immediate subterm, with the current position moved to it for the duration of the step.
+    pub(crate) fn descend<R>(&mut self, direction: Direction, step: impl FnOnce(&mut Self) -> R) -> R {
+        self.path.push(direction);
+        let result = step(self);
+        self.path.pop();
+        result
+    }
+
+    /// Contracts the β-redex at the current position, and returns `false` if the [LocalNamelessTerm] is not a β-redex.
+    /// 
+    /// If more than one β-redex is contracted in the same step, the recorded location is the path to the smallest subterm containing all of them.
+    pub(crate) fn contract<T: Clone>(&mut self, term: &mut LocalNamelessTerm<T>) -> bool {
+        let LocalNamelessTerm::App(func, arg) = term else {
+            return false;
+        };
+        let LocalNamelessTerm::Abs(_, body) = func.as_mut() else {
+            return false;
+        };
+        if let Some(stats) = &mut self.stats {
+            let occurrences = bound_occurrences(body, 0);
+            stats.substitutions += occurrences;
+            stats.nodes_copied += occurrences * arg.size();
+        }
+        body.substitute_index(0, arg);
+        // The body is replaced with a dummy value but the entire abstraction ceases to exist and cannot be accessed again, so this is ok
+        *term = mem::replace(body, LocalNamelessTerm::var(Var::Bound(0)));
+        self.location = Some(match self.location.take() {
+            Some(mut location) => {
+                let common = location.iter()
+                    .zip(&self.path)
+                    .take_while(|(recorded, current)| recorded == current)
+                    .count();
+                location.truncate(common);
+                location
+            },
+            None => self.path.clone(),
+        });
+        true
+    }
+
+    /// Returns the recorded location, or [None] if no β-redex was contracted.
+    pub(crate) fn into_location(self) -> Option<Vec<Direction>> {
+        self.location
+    }
+}
+
+fn bound_occurrences<T>(term: &LocalNamelessTerm<T>, index: usize) -> usize {
+    match term {
+        LocalNamelessTerm::Var(Var::Bound(other)) => usize::from(*other == index),
+        LocalNamelessTerm::Var(Var::Free(_)) => 0,
+        LocalNamelessTerm::Abs(_, body) => bound_occurrences(body, index + 1),
+        LocalNamelessTerm::App(func, arg) => bound_occurrences(func, index) + bound_occurrences(arg, index),
+    }
+}
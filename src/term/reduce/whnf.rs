@@ -0,0 +1,64 @@
+//! The weak head normal form β-reduction strategy.
+
+use crate::BetaReduce;
+use crate::Direction;
+use crate::LocalNamelessTerm;
+use crate::ReduceStats;
+use crate::StepTrace;
+use crate::Var;
+
+/// A β-reduction strategy for [Term](crate::Term)s that only reduces them to weak head normal form.
+/// 
+/// This strategy contracts the β-redex at the head of the application spine, and nothing else.
+/// It never reduces inside the bodies of abstractions or reduces arguments, so β-reduction stops as soon as the [Term](crate::Term) is an abstraction or an application whose head is a variable.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lamb::*;
+/// 
+/// let mut term = LocalNamelessTerm::from(&lambda!((λ x. x) (λ y. y)));
+/// assert!(term.beta_reduce_step(&Whnf));
+/// assert_eq!(Term::try_from(&term).unwrap(), lambda!(λ y. y));
+/// assert!(!term.beta_reduce_step(&Whnf));
+/// 
+/// let mut term = LocalNamelessTerm::from(&lambda!(λ x. (λ y. y) x));
+/// assert_eq!(Whnf.beta_reduce(&mut term), 0);
+/// 
+/// let mut term = LocalNamelessTerm::from(&lambda!((λ x. f x) ((λ y. y) a)));
+/// assert_eq!(Whnf.beta_reduce(&mut term), 1);
+/// assert_eq!(Term::try_from(&term).unwrap(), lambda!(f ((λ y. y) a)));
+/// 
+/// let mut term = LocalNamelessTerm::from(&lambda!((λ x. λ y. f x) a b));
+/// assert_eq!(Whnf.beta_reduce_step_located(&mut term), Some(vec![Direction::Func]));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Whnf;
+
+impl<T: Clone> BetaReduce<Var<T>> for Whnf {
+    fn beta_reduce_step(&self, term: &mut LocalNamelessTerm<T>) -> bool {
+        self.beta_reduce_step_traced(term, &mut StepTrace::default())
+    }
+
+    fn beta_reduce_step_located(&self, term: &mut LocalNamelessTerm<T>) -> Option<Vec<Direction>> {
+        let mut trace = StepTrace::default();
+        self.beta_reduce_step_traced(term, &mut trace);
+        trace.into_location()
+    }
+
+    fn beta_reduce_step_with_stats(&self, term: &mut LocalNamelessTerm<T>, stats: &mut ReduceStats) -> bool {
+        self.beta_reduce_step_traced(term, &mut StepTrace::with_stats(stats))
+    }
+}
+
+impl Whnf {
+    pub(crate) fn beta_reduce_step_traced<T: Clone>(&self, term: &mut LocalNamelessTerm<T>, trace: &mut StepTrace<'_>) -> bool {
+        match term {
+            LocalNamelessTerm::App(func, _) if !matches!(func.as_ref(), LocalNamelessTerm::Abs(_, _)) => {
+                trace.descend(Direction::Func, |trace| self.beta_reduce_step_traced(func, trace))
+            },
+            // Variables and abstractions are not β-redexes, so nothing is contracted for them
+            _ => trace.contract(term),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! α-conversion (renaming of formal parameters) of [Term]s.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::BetaReduce;
+use crate::Fresh;
+use crate::LocalNamelessTerm;
+use crate::ReducedTerm;
+use crate::Term;
+use crate::TraversalOrder;
+use crate::Var;
+
+impl<T: Clone + Eq> Term<T> {
+    /// Returns an α-equivalent version of the [Term] whose formal parameters are renamed using a naming scheme.
+    /// 
+    /// The naming scheme is called once for every abstraction, in the order the abstractions appear in the [Term] (outermost and leftmost first), with the number of abstractions renamed so far.
+    /// Every bound variable is renamed along with the formal parameter it is bound to, while free variables are left untouched.
+    /// 
+    /// The binding structure of the [Term] is only preserved if the naming scheme produces distinct identifiers that do not clash with any of the [Term]'s free variables.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!(λ x. λ y. x y z);
+    /// let renamed = term.alpha_rename_with(|index| ["a", "b", "c"][index]);
+    /// assert_eq!(renamed, lambda!(λ a. λ b. a b z));
+    /// ```
+    pub fn alpha_rename_with<F: FnMut(usize) -> T>(&self, mut scheme: F) -> Term<T> {
+        self.renamed_with(&mut scheme, &mut 0, &mut Vec::new())
+    }
+
+    fn renamed_with<'t, F: FnMut(usize) -> T>(&'t self, scheme: &mut F, count: &mut usize, scope: &mut Vec<(&'t T, T)>) -> Term<T> {
+        match self {
+            Self::Var(var) => match scope.iter().rev().find(|(param, _)| *param == var) {
+                Some((_, renamed)) => Term::var(renamed.clone()),
+                None => Term::var(var.clone()),
+            },
+            Self::Abs(param, body) => {
+                let renamed = scheme(*count);
+                *count += 1;
+                scope.push((param, renamed.clone()));
+                let body = body.renamed_with(scheme, count, scope);
+                scope.pop();
+                Term::abs(renamed, body)
+            },
+            Self::App(func, arg) => {
+                let func = func.renamed_with(scheme, count, scope);
+                Term::app(func, arg.renamed_with(scheme, count, scope))
+            },
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash + Fresh> Term<T> {
+    /// Returns a version of the [Term] β-reduced up to a certain limit using the specified [BetaReduce] `impl`ementation, wrapped in a [ReducedTerm], with its formal parameters renamed for readability.
+    /// 
+    /// β-reduction keeps the original identifiers of formal parameters, so the result of [Term::beta_reduced_limit] may contain several nested abstractions with the same formal parameter, or formal parameters that share identifiers with free variables.
+    /// Here, each formal parameter is instead renamed to a fresh identifier (see [Fresh]) if it clashes with a free variable or an earlier formal parameter, so that no two abstractions share a formal parameter and no free variable is captured.
+    /// Formal parameters that do not clash keep the identifiers they had in the original [Term], so the result stays recognisable - no separate mapping from the original [Term]'s identifiers is needed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda_str!((λ x. λ y. x) (λ y. y));
+    /// assert_eq!(term.beta_reduced_limit(10, &Normal::default()).term.to_string(), "λy. λy. y");
+    /// assert_eq!(term.beta_reduced_readable(10, &Normal::default()).term.to_string(), "λy. λy1. y1");
+    /// 
+    /// let term = lambda_str!((λ x. λ y. x y) y);
+    /// assert_eq!(term.beta_reduced_readable(10, &Normal::default()).term.to_string(), "λy1. y y1");
+    /// 
+    /// let term = lambda_str!((λ f. λ longname. f longname) (λ other. other));
+    /// assert_eq!(term.beta_reduced_readable(10, &Normal::default()).term.to_string(), "λlongname. longname");
+    /// ```
+    pub fn beta_reduced_readable<B: BetaReduce<Var<T>>>(&self, limit: usize, reducer: &B) -> ReducedTerm<T> {
+        let mut local_nameless = LocalNamelessTerm::from(self);
+        let count = local_nameless.beta_reduce_limit(limit, reducer);
+
+        let mut taken = local_nameless.traverse(TraversalOrder::PreOrder)
+            .filter_map(|term| match term {
+                Term::Var(Var::Free(var)) => Some(var.clone()),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+        let mut params = Vec::new();
+        local_nameless.fresh_params_into(&mut taken, &mut params);
+
+        ReducedTerm {
+            count,
+            term: local_nameless.to_classic_with_names(&mut params.into_iter()).unwrap(),
+        }
+    }
+
+    /// Renames every formal parameter of the [Term] whose identifier is in a set of identifiers to avoid, along with the bound variables referring to it.
+    /// 
+    /// Each such formal parameter is renamed to a fresh identifier (see [Fresh]) that is neither in the set nor used anywhere else in the [Term], so the result is always α-equivalent to the original.
+    /// This is useful before merging the [Term] into another one, such as by substituting it for a variable, since its abstractions can then no longer capture the other [Term]'s variables.
+    /// Free variables are never renamed, even if their identifiers are in the set.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::collections::HashSet;
+    /// 
+    /// use lamb::*;
+    /// 
+    /// let mut term = lambda_str!(λ x. λ y. x y z);
+    /// term.avoid_names(&HashSet::from(["x".to_string(), "z".to_string()]));
+    /// assert_eq!(term, lambda_str!(λ x1. λ y. x1 y z));
+    /// ```
+    pub fn avoid_names(&mut self, avoid: &HashSet<T>) {
+        let mut taken = self.names()
+            .into_iter()
+            .cloned()
+            .collect::<HashSet<_>>();
+        taken.extend(avoid.iter().cloned());
+        self.avoid_names_in(avoid, &mut taken, &mut Vec::new());
+    }
+
+    fn avoid_names_in(&mut self, avoid: &HashSet<T>, taken: &mut HashSet<T>, scope: &mut Vec<(T, Option<T>)>) {
+        match self {
+            Self::Var(var) => {
+                if let Some((_, Some(renamed))) = scope.iter().rev().find(|(param, _)| param == var) {
+                    *var = renamed.clone();
+                }
+            },
+            Self::Abs(param, body) => {
+                let renamed = avoid.contains(param).then(|| {
+                    let renamed = param.freshen(|name| taken.contains(name));
+                    taken.insert(renamed.clone());
+                    renamed
+                });
+                scope.push((param.clone(), renamed.clone()));
+                body.avoid_names_in(avoid, taken, scope);
+                scope.pop();
+                if let Some(renamed) = renamed {
+                    *param = renamed;
+                }
+            },
+            Self::App(func, arg) => {
+                func.avoid_names_in(avoid, taken, scope);
+                arg.avoid_names_in(avoid, taken, scope);
+            },
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash + Fresh> LocalNamelessTerm<T> {
+    // Formal parameters are named in the same order that LocalNamelessTerm::to_classic_with_names consumes names
+    fn fresh_params_into(&self, taken: &mut HashSet<T>, params: &mut Vec<T>) {
+        match self {
+            Self::Var(_) => (),
+            Self::Abs(param, body) => {
+                let param = match param {
+                    Var::Free(param) => param.freshen(|name| taken.contains(name)),
+                    Var::Bound(_) => T::fresh(|name| taken.contains(name)),
+                };
+                taken.insert(param.clone());
+                params.push(param);
+                body.fresh_params_into(taken, params);
+            },
+            Self::App(func, arg) => {
+                func.fresh_params_into(taken, params);
+                arg.fresh_params_into(taken, params);
+            },
+        }
+    }
+}
@@ -0,0 +1,360 @@
+//! Hash-consed [SharedTerm]s, in which structurally equal subterms share storage.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Write;
+use std::hash::Hash;
+use std::ptr;
+use std::rc::Rc;
+
+use crate::Fresh;
+use crate::Fuel;
+use crate::Term;
+
+/// A lambda calculus term whose subterms are reference-counted, allowing structurally equal subterms to share the same allocation.
+/// 
+/// [SharedTerm]s are usually produced by [SharedTerm::share], which hash-conses a [Term] so that each of its structurally distinct subterms is stored exactly once.
+/// This is especially useful for [Term]s produced by β-reduction, where substitution can duplicate large arguments several times (such as with the duplicating combinator `W`).
+/// 
+/// A [SharedTerm] can be converted back to a regular [Term] using its [From] `impl`ementation, which un-shares every subterm.
+#[derive(Debug, Eq, PartialEq)]
+pub enum SharedTerm<T> {
+    /// A variable, which may be either free or bound to an abstraction's formal parameter.
+    Var(T),
+    /// An abstraction, binding a formal parameter inside its (possibly shared) body.
+    Abs(T, Rc<Self>),
+    /// An application of one (possibly shared) [SharedTerm] to another.
+    App(Rc<Self>, Rc<Self>),
+}
+
+impl<T: Clone + Eq + Hash> SharedTerm<T> {
+    /// Converts a [Term] to a [SharedTerm], sharing every structurally equal subterm.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::rc::Rc;
+    /// 
+    /// use lamb::*;
+    /// 
+    /// let id = lambda!(λ x. x);
+    /// let term = app!(id.clone(), id);
+    /// 
+    /// let shared = SharedTerm::share(&term);
+    /// match shared.as_ref() {
+    ///     SharedTerm::App(func, arg) => assert!(Rc::ptr_eq(func, arg)),
+    ///     _ => unreachable!(),
+    /// }
+    /// assert_eq!(Term::from(shared.as_ref()), term);
+    /// ```
+    pub fn share(term: &Term<T>) -> Rc<Self> {
+        Self::share_in(term, &mut HashMap::new())
+    }
+
+    fn share_in(term: &Term<T>, table: &mut HashMap<Node<T>, Rc<Self>>) -> Rc<Self> {
+        // Subterms are shared before their parents, so they can be identified by their address alone
+        let (node, shared) = match term {
+            Term::Var(var) => (Node::Var(var.clone()), Self::Var(var.clone())),
+            Term::Abs(param, body) => {
+                let body = Self::share_in(body, table);
+                (Node::Abs(param.clone(), Rc::as_ptr(&body)), Self::Abs(param.clone(), body))
+            },
+            Term::App(func, arg) => {
+                let func = Self::share_in(func, table);
+                let arg = Self::share_in(arg, table);
+                (Node::App(Rc::as_ptr(&func), Rc::as_ptr(&arg)), Self::App(func, arg))
+            },
+        };
+        table.entry(node)
+            .or_insert_with(|| Rc::new(shared))
+            .clone()
+    }
+}
+
+impl<T: Clone + Eq + Hash + Fresh> SharedTerm<T> {
+    /// Reduces a [SharedTerm] to its β-normal form using normal order, or returns [None] if the [Fuel] runs out first.
+    /// 
+    /// Each contraction consumes one unit of [Fuel].
+    /// The weak head normal form and β-normal form of every distinct node are computed at most once and then reused wherever that node is shared, so an argument that is duplicated by substitution is only ever reduced once.
+    /// Substitution is likewise performed once per distinct node rather than once per occurrence, and formal parameters are renamed (see [Fresh]) where they would otherwise capture a free variable.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// // The argument is duplicated three times, but only reduced once
+    /// let term = lambda_str!((λ x. f x x x) ((λ y. y) a));
+    /// let mut fuel = Fuel::new(2);
+    /// let normal = SharedTerm::normalize(&SharedTerm::share(&term), &mut fuel).unwrap();
+    /// assert_eq!(Term::from(normal.as_ref()), lambda_str!(f a a a));
+    /// assert!(fuel.is_empty());
+    /// 
+    /// let term = lambda_str!((λ x. λ y. x y) y);
+    /// let normal = SharedTerm::normalize(&SharedTerm::share(&term), &mut Fuel::unlimited()).unwrap();
+    /// assert_eq!(Term::from(normal.as_ref()).to_string(), "λy1. y y1");
+    /// 
+    /// let omega = lambda_str!((λ x. x x) (λ x. x x));
+    /// assert_eq!(SharedTerm::normalize(&SharedTerm::share(&omega), &mut Fuel::new(100)), None);
+    /// ```
+    pub fn normalize(term: &Rc<Self>, fuel: &mut Fuel) -> Option<Rc<Self>> {
+        Normalizer::default().normal(term, fuel)
+    }
+}
+
+// The minimum number of nodes a repeated subterm needs for Term::display_with_sharing to label it
+const SHARING_THRESHOLD: usize = 5;
+
+impl<T: Clone + Display + Eq + Hash> Term<T> {
+    /// Returns the [Term] formatted like its [Display] `impl`ementation, but with large repeated subterms printed only once.
+    /// 
+    /// Every structurally equal subterm of at least five nodes that occurs more than once is given a label such as `@1`, defined once at the start (`let @1 = ... in ...`) and referred to by its label everywhere else.
+    /// Only the outermost repeated subterms are labelled, and labels are numbered in order of their first occurrence.
+    /// 
+    /// Since labels are defined outside every abstraction, occurrences of a subterm that use variables bound by an enclosing abstraction are never replaced by a label, as that would make those variables appear free.
+    /// 
+    /// The labels are purely textual, so the result is meant for reading rather than for parsing back into a [Term].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = lambda!(f (λ x. x x x) (λ x. x x x));
+    /// assert_eq!(term.display_with_sharing(), "let @1 = λx. x x x in f @1 @1");
+    /// 
+    /// let term = lambda!(f (λ x. x) (λ x. x));
+    /// assert_eq!(term.display_with_sharing(), term.to_string());
+    /// 
+    /// // The repeated subterm uses y, which is bound outside it
+    /// let term = lambda!(λ y. f (λ x. x y y) (λ x. x y y));
+    /// assert_eq!(term.display_with_sharing(), term.to_string());
+    /// 
+    /// let term = lambda!(λ y. f (λ x. x x x) (λ x. x x x));
+    /// assert_eq!(term.display_with_sharing(), "let @1 = λx. x x x in λy. f @1 @1");
+    /// ```
+    pub fn display_with_sharing(&self) -> String {
+        let shared = SharedTerm::share(self);
+        let mut free_vars = HashMap::new();
+        let mut uses = HashMap::new();
+        shared.count_uses(&mut Vec::new(), &mut free_vars, &mut uses);
+
+        let mut labels = Vec::new();
+        let labelled = shared.labelled(&mut Vec::new(), &free_vars, &uses, &mut labels);
+
+        let mut output = String::new();
+        for (index, subterm) in labels.iter().enumerate() {
+            // Writing to a String never fails
+            write!(output, "let @{} = {} in ", index + 1, Term::from(*subterm)).unwrap();
+        }
+        write!(output, "{}", labelled).unwrap();
+        output
+    }
+}
+
+impl<T: Display + Eq + Hash> SharedTerm<T> {
+    // Records how many times each distinct subterm is used in a position where it could be labelled and how many nodes it has, returning the latter
+    fn count_uses<'s>(&'s self, bound: &mut Vec<&'s T>, free_vars: &mut HashMap<*const Self, HashSet<&'s T>>, uses: &mut HashMap<*const Self, (usize, usize)>) -> usize {
+        let liftable = self.is_liftable(bound, free_vars);
+        if liftable {
+            if let Some((count, size)) = uses.get_mut(&(self as *const Self)) {
+                *count += 1;
+                return *size;
+            }
+        }
+        let size = match self {
+            Self::Var(_) => 1,
+            Self::Abs(param, body) => {
+                bound.push(param);
+                let size = 1 + body.count_uses(bound, free_vars, uses);
+                bound.pop();
+                size
+            },
+            Self::App(func, arg) => 1 + func.count_uses(bound, free_vars, uses) + arg.count_uses(bound, free_vars, uses),
+        };
+        if liftable {
+            uses.insert(self, (1, size));
+        }
+        size
+    }
+
+    fn labelled<'s>(&'s self, bound: &mut Vec<&'s T>, free_vars: &HashMap<*const Self, HashSet<&'s T>>, uses: &HashMap<*const Self, (usize, usize)>, labels: &mut Vec<&'s Self>) -> Term<String> {
+        let liftable = !free_vars[&(self as *const Self)].iter().any(|var| bound.contains(var));
+        match uses.get(&(self as *const Self)) {
+            Some(&(count, size)) if liftable && count > 1 && size >= SHARING_THRESHOLD => {
+                let index = match labels.iter().position(|label| ptr::eq(*label, self)) {
+                    Some(index) => index,
+                    None => {
+                        labels.push(self);
+                        labels.len() - 1
+                    },
+                };
+                return Term::var(format!("@{}", index + 1));
+            },
+            _ => (),
+        }
+        match self {
+            Self::Var(var) => Term::var(var.to_string()),
+            Self::Abs(param, body) => {
+                bound.push(param);
+                let body = body.labelled(bound, free_vars, uses, labels);
+                bound.pop();
+                Term::abs(param.to_string(), body)
+            },
+            Self::App(func, arg) => Term::app(func.labelled(bound, free_vars, uses, labels), arg.labelled(bound, free_vars, uses, labels)),
+        }
+    }
+
+    // Checks whether none of the subterm's free variables are bound by an enclosing abstraction, so that it can be moved outside all of them
+    fn is_liftable<'s>(&'s self, bound: &[&'s T], free_vars: &mut HashMap<*const Self, HashSet<&'s T>>) -> bool {
+        !self.free_vars_memoized(free_vars)
+            .iter()
+            .any(|var| bound.contains(var))
+    }
+
+    fn free_vars_memoized<'s, 'm>(&'s self, free_vars: &'m mut HashMap<*const Self, HashSet<&'s T>>) -> &'m HashSet<&'s T> {
+        if !free_vars.contains_key(&(self as *const Self)) {
+            let vars = match self {
+                Self::Var(var) => HashSet::from([var]),
+                Self::Abs(param, body) => {
+                    let mut vars = body.free_vars_memoized(free_vars).clone();
+                    vars.remove(param);
+                    vars
+                },
+                Self::App(func, arg) => {
+                    let mut vars = func.free_vars_memoized(free_vars).clone();
+                    vars.extend(arg.free_vars_memoized(free_vars));
+                    vars
+                },
+            };
+            free_vars.insert(self, vars);
+        }
+        &free_vars[&(self as *const Self)]
+    }
+}
+
+impl<T: Clone> From<&SharedTerm<T>> for Term<T> {
+    fn from(shared: &SharedTerm<T>) -> Self {
+        match shared {
+            SharedTerm::Var(var) => Term::var(var.clone()),
+            SharedTerm::Abs(param, body) => Term::abs(param.clone(), body.as_ref().into()),
+            SharedTerm::App(func, arg) => Term::app(func.as_ref().into(), arg.as_ref().into()),
+        }
+    }
+}
+
+// The table used for hash-consing keeps every shared node alive, so their addresses are never reused
+#[derive(Eq, Hash, PartialEq)]
+enum Node<T> {
+    Var(T),
+    Abs(T, *const SharedTerm<T>),
+    App(*const SharedTerm<T>, *const SharedTerm<T>),
+}
+
+// Every memoized result is stored alongside the node it belongs to, which keeps that node alive so its address is never reused
+type Memo<T, V> = HashMap<*const SharedTerm<T>, (Rc<SharedTerm<T>>, V)>;
+
+struct Normalizer<T> {
+    whnf: Memo<T, Rc<SharedTerm<T>>>,
+    normal: Memo<T, Rc<SharedTerm<T>>>,
+    free_vars: Memo<T, Rc<HashSet<T>>>,
+}
+
+impl<T> Default for Normalizer<T> {
+    fn default() -> Self {
+        Self {
+            whnf: HashMap::new(),
+            normal: HashMap::new(),
+            free_vars: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash + Fresh> Normalizer<T> {
+    fn normal(&mut self, term: &Rc<SharedTerm<T>>, fuel: &mut Fuel) -> Option<Rc<SharedTerm<T>>> {
+        if let Some((_, normal)) = self.normal.get(&Rc::as_ptr(term)) {
+            return Some(normal.clone());
+        }
+        let whnf = self.whnf(term, fuel)?;
+        let normal = match whnf.as_ref() {
+            SharedTerm::Var(_) => whnf.clone(),
+            SharedTerm::Abs(param, body) => Rc::new(SharedTerm::Abs(param.clone(), self.normal(body, fuel)?)),
+            // The function of an application in weak head normal form cannot become an abstraction, so both sides are normalized independently
+            SharedTerm::App(func, arg) => Rc::new(SharedTerm::App(self.normal(func, fuel)?, self.normal(arg, fuel)?)),
+        };
+        self.normal.insert(Rc::as_ptr(term), (term.clone(), normal.clone()));
+        Some(normal)
+    }
+
+    fn whnf(&mut self, term: &Rc<SharedTerm<T>>, fuel: &mut Fuel) -> Option<Rc<SharedTerm<T>>> {
+        if let Some((_, whnf)) = self.whnf.get(&Rc::as_ptr(term)) {
+            return Some(whnf.clone());
+        }
+        let whnf = match term.as_ref() {
+            SharedTerm::Var(_) | SharedTerm::Abs(..) => term.clone(),
+            SharedTerm::App(func, arg) => {
+                let func = self.whnf(func, fuel)?;
+                match func.as_ref() {
+                    SharedTerm::Abs(param, body) => {
+                        if !fuel.consume() {
+                            return None;
+                        }
+                        let contracted = self.substitute(body, param, arg, &mut HashMap::new());
+                        self.whnf(&contracted, fuel)?
+                    },
+                    _ => Rc::new(SharedTerm::App(func.clone(), arg.clone())),
+                }
+            },
+        };
+        self.whnf.insert(Rc::as_ptr(term), (term.clone(), whnf.clone()));
+        Some(whnf)
+    }
+
+    // Substitutions are memoized per node, which lives at least as long as the body being substituted into
+    fn substitute(&mut self, term: &Rc<SharedTerm<T>>, var: &T, value: &Rc<SharedTerm<T>>, substituted: &mut HashMap<*const SharedTerm<T>, Rc<SharedTerm<T>>>) -> Rc<SharedTerm<T>> {
+        if let Some(result) = substituted.get(&Rc::as_ptr(term)) {
+            return result.clone();
+        }
+        let result = if !self.free_vars(term).contains(var) {
+            term.clone()
+        } else {
+            match term.as_ref() {
+                SharedTerm::Var(_) => value.clone(),
+                SharedTerm::Abs(param, body) if self.free_vars(value).contains(param) => {
+                    let value_vars = self.free_vars(value);
+                    let body_vars = self.free_vars(body);
+                    let fresh = param.freshen(|name| value_vars.contains(name) || body_vars.contains(name));
+                    let renamed = self.substitute(body, param, &Rc::new(SharedTerm::Var(fresh.clone())), &mut HashMap::new());
+                    Rc::new(SharedTerm::Abs(fresh, self.substitute(&renamed, var, value, substituted)))
+                },
+                SharedTerm::Abs(param, body) => Rc::new(SharedTerm::Abs(param.clone(), self.substitute(body, var, value, substituted))),
+                SharedTerm::App(func, arg) => Rc::new(SharedTerm::App(self.substitute(func, var, value, substituted), self.substitute(arg, var, value, substituted))),
+            }
+        };
+        substituted.insert(Rc::as_ptr(term), result.clone());
+        result
+    }
+
+    fn free_vars(&mut self, term: &Rc<SharedTerm<T>>) -> Rc<HashSet<T>> {
+        if let Some((_, vars)) = self.free_vars.get(&Rc::as_ptr(term)) {
+            return vars.clone();
+        }
+        let vars = match term.as_ref() {
+            SharedTerm::Var(var) => HashSet::from([var.clone()]),
+            SharedTerm::Abs(param, body) => {
+                let mut vars = self.free_vars(body).as_ref().clone();
+                vars.remove(param);
+                vars
+            },
+            SharedTerm::App(func, arg) => {
+                let mut vars = self.free_vars(func).as_ref().clone();
+                vars.extend(self.free_vars(arg).iter().cloned());
+                vars
+            },
+        };
+        let vars = Rc::new(vars);
+        self.free_vars.insert(Rc::as_ptr(term), (term.clone(), vars.clone()));
+        vars
+    }
+}
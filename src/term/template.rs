@@ -0,0 +1,51 @@
+//! Instantiation of [Term]s used as templates.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::LocalNamelessTerm;
+use crate::Term;
+
+/// Represents possible errors that can occur when instantiating a template [Term] using [Term::instantiate].
+#[derive(Debug)]
+pub enum TemplateError<T> {
+    /// A hole was declared, but no [Term] was provided to fill it.
+    /// 
+    /// This contains the name of the first such hole.
+    MissingHole(T),
+}
+
+impl<T: Clone + Eq + Hash> Term<T> {
+    /// Treats the [Term] as a template, and returns a copy of it with every free occurrence of each declared hole replaced by the [Term] provided for it.
+    /// 
+    /// Only the free variables named in `holes` are considered holes, so any other free variables are left untouched even if a [Term] is provided for them.
+    /// Unlike replacing free variables with bindings, every declared hole must be filled - if a hole has no matching [Term], a [TemplateError::MissingHole] is returned instead.
+    /// The filled-in [Term]s are not themselves instantiated, even if they contain holes.
+    /// 
+    /// Formal parameters of the template are not renamed, so free variables of the filled-in [Term]s should not share identifiers with them.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::collections::HashMap;
+    /// 
+    /// use lamb::*;
+    /// 
+    /// let template = lambda!(λ x. hole x);
+    /// let fills = HashMap::from([("hole", var!(f))]);
+    /// assert_eq!(template.instantiate(&["hole"], &fills).unwrap(), lambda!(λ x. f x));
+    /// 
+    /// assert!(matches!(template.instantiate(&["hole", "other"], &fills), Err(TemplateError::MissingHole("other"))));
+    /// ```
+    pub fn instantiate(&self, holes: &[T], fills: &HashMap<T, Term<T>>) -> Result<Term<T>, TemplateError<T>> {
+        let mut binds = holes.iter()
+            .map(|hole| match fills.get(hole) {
+                Some(fill) => Ok((hole.clone(), LocalNamelessTerm::from(fill))),
+                None => Err(TemplateError::MissingHole(hole.clone())),
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        let mut local_nameless = LocalNamelessTerm::from(self);
+        local_nameless.rebind(&mut binds);
+        Ok(Term::try_from(&local_nameless).unwrap())
+    }
+}
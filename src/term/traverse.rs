@@ -0,0 +1,84 @@
+//! Traversals over the subterms of [Term]s.
+
+use std::collections::VecDeque;
+
+use crate::Term;
+
+/// The order in which [Term::traverse] visits subterms.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TraversalOrder {
+    /// Visit each [Term] before its subterms, and function subterms before argument subterms.
+    PreOrder,
+    /// Visit each [Term] after its subterms, and function subterms before argument subterms.
+    PostOrder,
+    /// Visit [Term]s level by level, from the root downwards and from left to right.
+    BreadthFirst,
+}
+
+impl<T> Term<T> {
+    /// Returns an iterator over the [Term] and all of its subterms, visited in the specified [TraversalOrder].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use lamb::*;
+    /// 
+    /// let term = app!(var!(x), var!(y));
+    /// let post_order = term.traverse(TraversalOrder::PostOrder).collect::<Vec<_>>();
+    /// assert_eq!(post_order, vec![&var!(x), &var!(y), &term]);
+    /// 
+    /// let term = lambda!((λ x. x) y);
+    /// let pre_order = term.traverse(TraversalOrder::PreOrder).map(ToString::to_string).collect::<Vec<_>>();
+    /// assert_eq!(pre_order, vec!["(λx. x) y", "λx. x", "x", "y"]);
+    /// let breadth_first = term.traverse(TraversalOrder::BreadthFirst).map(ToString::to_string).collect::<Vec<_>>();
+    /// assert_eq!(breadth_first, vec!["(λx. x) y", "λx. x", "y", "x"]);
+    /// ```
+    pub fn traverse(&self, order: TraversalOrder) -> impl Iterator<Item = &Self> {
+        Traverse {
+            order,
+            pending: VecDeque::from([(self, false)]),
+        }
+    }
+
+    fn children(&self) -> impl DoubleEndedIterator<Item = &Self> {
+        let (first, second) = match self {
+            Self::Var(_) => (None, None),
+            Self::Abs(_, body) => (Some(body.as_ref()), None),
+            Self::App(func, arg) => (Some(func.as_ref()), Some(arg.as_ref())),
+        };
+        first.into_iter().chain(second)
+    }
+}
+
+struct Traverse<'t, T> {
+    order: TraversalOrder,
+    // Each pending term is paired with whether its subterms have already been queued, which only matters for post-order traversals
+    pending: VecDeque<(&'t Term<T>, bool)>,
+}
+
+impl<'t, T> Iterator for Traverse<'t, T> {
+    type Item = &'t Term<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.order {
+            TraversalOrder::PreOrder => {
+                let (term, _) = self.pending.pop_back()?;
+                self.pending.extend(term.children().rev().map(|child| (child, false)));
+                Some(term)
+            },
+            TraversalOrder::PostOrder => loop {
+                let (term, expanded) = self.pending.pop_back()?;
+                if expanded || matches!(term, Term::Var(_)) {
+                    break Some(term);
+                }
+                self.pending.push_back((term, true));
+                self.pending.extend(term.children().rev().map(|child| (child, false)));
+            },
+            TraversalOrder::BreadthFirst => {
+                let (term, _) = self.pending.pop_front()?;
+                self.pending.extend(term.children().map(|child| (child, false)));
+                Some(term)
+            },
+        }
+    }
+}